@@ -0,0 +1,399 @@
+//! A JSON API over HTTP on a local address, for bots and tooling to observe and play the running
+//! game:
+//!
+//! ```text
+//! GET  /state              the settlement on screen: economy, nihilists and recent headlines
+//! GET  /state?settlement=1 another settlement
+//! POST /plus               assign a comrade, the body being the view to do it from
+//! POST /minus              unassign one
+//! POST /action             any other action, as `{"action": "Retarget", "view": {...}}`
+//! POST /pause              stop the clock
+//! POST /resume             start it again
+//! POST /step               move on by a day, paused or not
+//! ```
+//!
+//! Views are the same as those sent by co-op players, e.g. `{"control": [1, 0]}` for the agitators
+//! of the first building. Anything left out is taken as zero, on the main screen. The player's own
+//! screen is left alone. Views of things that aren't there are answered with a 400, and bodies
+//! over 4 KB with a 413.
+//!
+//! The state tells no more than the player's screen does: the economy is only known through the
+//! nihilists' intel, and informants don't give themselves away.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::mpsc::{self, Receiver, Sender},
+    time::Duration,
+};
+
+use serde_json::{json, Value};
+
+use crate::{
+    coop::{Action, Request, View},
+    state::State,
+};
+
+/// Headlines included with the state
+const HEADLINES: usize = 20;
+/// Largest request body taken, in bytes
+const MAX_BODY: usize = 4096;
+/// How long a caller gets to send their request
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Debug)]
+enum Call {
+    State(Option<usize>),
+    Act(Request),
+    Pause,
+    Resume,
+    Step,
+}
+
+/// A status code and a JSON body
+type Response = (u16, Value);
+
+pub struct Api {
+    calls: Receiver<(Call, Sender<Response>)>,
+    addr: SocketAddr,
+    pub paused: bool,
+}
+
+impl Api {
+    /// Takes calls on the given address in the background
+    pub fn listen(addr: &str) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let (sender, calls) = mpsc::channel();
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming().map_while(Result::ok) {
+                let sender = sender.clone();
+                std::thread::spawn(move || handle(stream, sender));
+            }
+        });
+
+        Ok(Self {
+            calls,
+            addr,
+            paused: false,
+        })
+    }
+
+    /// The address calls come in on
+    pub fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Answers whatever calls came in since last time
+    pub fn serve(&mut self, state: &mut State) {
+        let calls: Vec<_> = self.calls.try_iter().collect();
+
+        for (call, reply) in calls {
+            let response = match call {
+                Call::State(settlement) => self.state(state, settlement),
+                Call::Act(request) => match request.view.validate(state) {
+                    Ok(()) => {
+                        // Act from the caller's view, then put the player's back
+                        let view = View::of(state);
+                        request.view.show(state);
+                        request.action.apply(state);
+                        view.show(state);
+
+                        (200, json!({ "ok": true }))
+                    }
+                    Err(error) => (400, json!({ "error": error })),
+                },
+                Call::Pause => {
+                    self.paused = true;
+                    (200, json!({ "paused": true }))
+                }
+                Call::Resume => {
+                    self.paused = false;
+                    (200, json!({ "paused": false }))
+                }
+                Call::Step => {
+                    *state = state.next();
+                    (200, json!({ "day": state.economy().day }))
+                }
+            };
+
+            let _ = reply.send(response);
+        }
+    }
+
+    fn state(&self, state: &State, settlement: Option<usize>) -> Response {
+        let index = settlement.unwrap_or(state.settlement);
+        let Some(settlement) = state.world.settlements.get(index) else {
+            return (404, json!({ "error": "No such settlement" }));
+        };
+
+        let headlines: Vec<&String> = settlement.headlines.iter().take(HEADLINES).collect();
+        let economy = &settlement.economy;
+
+        // Rivals keep to the shadows, and intel goes with the economy
+        let mut nihilists = json!(settlement.nihilists);
+        if let Some(nihilists) = nihilists.as_object_mut() {
+            nihilists.remove("competition");
+            nihilists.remove("intel");
+        }
+
+        (
+            200,
+            json!({
+                "paused": self.paused,
+                "settlement": index,
+                "name": settlement.name,
+                "settlements": state.world.settlements.iter().map(|s| &s.name).collect::<Vec<_>>(),
+                "extinct": state.extinct(),
+                "economy": {
+                    "day": economy.day,
+                    "calendar": economy.calendar(),
+                    "weather": economy.weather,
+                    "forecast": economy.forecast,
+                    "governor": economy.governor,
+                    "intel": settlement.nihilists.intel,
+                },
+                "nihilists": nihilists,
+                "headlines": headlines,
+            }),
+        )
+    }
+}
+
+/// Reads an HTTP request off the stream, passes it on to the game and writes back the answer
+fn handle(mut stream: TcpStream, calls: Sender<(Call, Sender<Response>)>) {
+    let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+
+    let (status, body) = match read(&mut stream) {
+        Ok(call) => {
+            let (reply, response) = mpsc::channel();
+            if calls.send((call, reply)).is_err() {
+                return;
+            }
+
+            match response.recv() {
+                Ok(response) => response,
+                Err(_) => return,
+            }
+        }
+        Err(response) => response,
+    };
+
+    let body = body.to_string();
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        413 => "Payload Too Large",
+        _ => "Method Not Allowed",
+    };
+
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason,
+        body.len(),
+        body
+    );
+}
+
+/// The call an HTTP request stands for
+fn read(stream: &mut TcpStream) -> Result<Call, Response> {
+    let bad = |error: &str| (400, json!({ "error": error }));
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader
+        .read_line(&mut request_line)
+        .map_err(|_| bad("Unreadable request"))?;
+    let mut words = request_line.split_whitespace();
+    let method = words.next().unwrap_or_default().to_string();
+    let target = words.next().unwrap_or_default().to_string();
+
+    let mut length = 0;
+    loop {
+        let mut header = String::new();
+        reader
+            .read_line(&mut header)
+            .map_err(|_| bad("Unreadable headers"))?;
+
+        let header = header.trim();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.trim().parse().map_err(|_| bad("Bad length"))?;
+            }
+        }
+    }
+
+    if length > MAX_BODY {
+        return Err((413, json!({ "error": "Body too large" })));
+    }
+
+    let mut body = vec![0; length];
+    reader
+        .read_exact(&mut body)
+        .map_err(|_| bad("Unreadable body"))?;
+
+    // Views can be left out altogether
+    let view = || -> Result<View, Response> {
+        if body.is_empty() {
+            return Ok(View::default());
+        }
+        serde_json::from_slice(&body).map_err(|e| bad(&e.to_string()))
+    };
+
+    let (path, query) = target.split_once('?').unwrap_or((&target, ""));
+
+    match (method.as_str(), path) {
+        ("GET", "/state") => {
+            let settlement = query
+                .split('&')
+                .find_map(|pair| pair.strip_prefix("settlement="))
+                .map(|s| s.parse().map_err(|_| bad("Bad settlement")))
+                .transpose()?;
+            Ok(Call::State(settlement))
+        }
+        ("POST", "/plus") => Ok(Call::Act(Request {
+            action: Action::Plus,
+            view: view()?,
+        })),
+        ("POST", "/minus") => Ok(Call::Act(Request {
+            action: Action::Minus,
+            view: view()?,
+        })),
+        ("POST", "/action") => serde_json::from_slice(&body)
+            .map(Call::Act)
+            .map_err(|e| bad(&e.to_string())),
+        ("POST", "/pause") => Ok(Call::Pause),
+        ("POST", "/resume") => Ok(Call::Resume),
+        ("POST", "/step") => Ok(Call::Step),
+        ("GET", _) | ("POST", _) => Err((404, json!({ "error": "No such endpoint" }))),
+        _ => Err((405, json!({ "error": "Only GET and POST are supported" }))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread::JoinHandle;
+
+    use super::*;
+    use crate::{
+        loopback,
+        operatives::Operative,
+        trials::{Charge, Trial},
+    };
+
+    /// Makes a call from another thread, returning the status and body of the answer
+    fn call(api: &Api, request: String) -> JoinHandle<(u16, Value)> {
+        let addr = api.local_addr();
+
+        std::thread::spawn(move || {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            stream.write_all(request.as_bytes()).unwrap();
+
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+
+            let (head, body) = response.split_once("\r\n\r\n").unwrap();
+            let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+            (status, serde_json::from_str(body).unwrap())
+        })
+    }
+
+    /// Answers calls until the caller has heard back
+    fn answer(api: &mut Api, state: &mut State, caller: JoinHandle<(u16, Value)>) -> (u16, Value) {
+        loopback::until(|| {
+            api.serve(state);
+            caller.is_finished()
+        });
+
+        caller.join().unwrap()
+    }
+
+    fn post(path: &str, body: &str) -> String {
+        format!(
+            "POST {} HTTP/1.1\r\nContent-Length: {}\r\n\r\n{}",
+            path,
+            body.len(),
+            body
+        )
+    }
+
+    #[test]
+    fn state_goes_through_intel() {
+        let mut state = State::seeded(1);
+        let mut api = Api::listen("127.0.0.1:0").unwrap();
+
+        let caller = call(&api, "GET /state HTTP/1.1\r\n\r\n".to_string());
+        let (status, body) = answer(&mut api, &mut state, caller);
+
+        assert_eq!(status, 200);
+        assert!(body["economy"]["intel"].is_object());
+        assert!(body["economy"].get("food").is_none());
+        assert!(body["nihilists"]["operatives"][0]
+            .get("informant")
+            .is_none());
+    }
+
+    #[test]
+    fn informants_in_the_dock_keep_their_cover() {
+        let mut state = State::seeded(1);
+        let mut api = Api::listen("127.0.0.1:0").unwrap();
+
+        let mut informant = Operative::random(&mut rand::thread_rng());
+        informant.informant = true;
+        state.world.settlements[0]
+            .nihilists
+            .trials
+            .push(Trial::new(vec![informant], Charge::Conspiracy));
+
+        let caller = call(&api, "GET /state HTTP/1.1\r\n\r\n".to_string());
+        let (status, body) = answer(&mut api, &mut state, caller);
+
+        assert_eq!(status, 200);
+        let defendant = &body["nihilists"]["trials"][0]["defendants"][0];
+        assert!(defendant.is_object());
+        assert!(defendant.get("informant").is_none());
+    }
+
+    #[test]
+    fn acts_from_the_view_given() {
+        let mut state = State::seeded(1);
+        let mut api = Api::listen("127.0.0.1:0").unwrap();
+        let target = state.nihilists().target.clone();
+
+        let caller = call(&api, post("/action", r#"{"action": "Retarget"}"#));
+        let (status, _) = answer(&mut api, &mut state, caller);
+
+        assert_eq!(status, 200);
+        assert_ne!(state.nihilists().target, target);
+    }
+
+    #[test]
+    fn bad_views_get_a_400() {
+        let mut state = State::seeded(1);
+        let mut api = Api::listen("127.0.0.1:0").unwrap();
+
+        let caller = call(&api, post("/plus", r#"{"control": [9, 0]}"#));
+        let (status, body) = answer(&mut api, &mut state, caller);
+
+        assert_eq!(status, 400);
+        assert!(body["error"].is_string());
+    }
+
+    #[test]
+    fn rejects_large_bodies() {
+        let mut state = State::seeded(1);
+        let mut api = Api::listen("127.0.0.1:0").unwrap();
+
+        let caller = call(&api, post("/plus", &" ".repeat(MAX_BODY + 1)));
+        let (status, _) = answer(&mut api, &mut state, caller);
+
+        assert_eq!(status, 413);
+    }
+}
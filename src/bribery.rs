@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use strum::EnumIter;
+
+/// Officials the nihilists can pay off. Bribes are paid up front and delivered the next day, when
+/// the official might still refuse and report it.
+#[derive(Clone, Debug, PartialEq, Eq, EnumIter, Serialize, Deserialize)]
+pub enum Bribe {
+    /// Looks the other way, lowering suspicion
+    Police,
+    /// Drags out open investigations
+    Magistrate,
+    /// Leaves the stores unguarded for a night
+    Quartermaster,
+}
+
+impl Bribe {
+    /// Price in rubles
+    pub fn cost(&self) -> usize {
+        match self {
+            Self::Police => 30,
+            Self::Magistrate => 40,
+            Self::Quartermaster => 60,
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Police => "Lower the government's suspicion",
+            Self::Magistrate => "Delay every open investigation",
+            Self::Quartermaster => "Raid the stores for a night",
+        }
+    }
+
+    pub fn headline(&self) -> &'static str {
+        match self {
+            Self::Police => "Police patrols thin out in the poorer quarters.",
+            Self::Magistrate => "Magistrate falls ill, investigations put on hold.",
+            Self::Quartermaster => "Stores raided overnight! The quartermaster saw nothing.",
+        }
+    }
+}
+
+impl std::fmt::Display for Bribe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Police => write!(f, "👮 Police"),
+            Self::Magistrate => write!(f, "⚖️ Magistrate"),
+            Self::Quartermaster => write!(f, "🗝️ Quartermaster"),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+/// Running totals for a single year of the settlement
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct YearStats {
+    pub year: usize,
+    pub births: usize,
+    pub deaths: usize,
+    pub immigrants: usize,
+    pub emigrants: usize,
+    // [Food, Wood, Stone, Iron]
+    pub production: [usize; 4],
+}
+
+impl YearStats {
+    pub fn new(year: usize) -> Self {
+        Self {
+            year,
+            ..Default::default()
+        }
+    }
+
+    /// The year in a headline, with every count passed through `rumour`
+    pub fn summary(&self, mut rumour: impl FnMut(usize) -> String) -> String {
+        format!(
+            "Year {} comes to an end: {} born, {} dead, {} arrived, {} left, 🍖 {} 🪵 {} 🪨 {} 🪙 {} produced.",
+            self.year,
+            rumour(self.births),
+            rumour(self.deaths),
+            rumour(self.immigrants),
+            rumour(self.emigrants),
+            rumour(self.production[0]),
+            rumour(self.production[1]),
+            rumour(self.production[2]),
+            rumour(self.production[3]),
+        )
+    }
+}
@@ -0,0 +1,591 @@
+//! Co-operative games over a local TCP socket. The server runs the game and every day sends each
+//! player a line of JSON with a snapshot of it, along with the buildings the player staffs.
+//! Players send back a line of JSON for every action they take, along with what they were looking
+//! at when they took it. Buildings are dealt out again whenever someone joins or leaves, and
+//! there are only enough for four players. Requests that make no sense are answered with a line
+//! of JSON with an `error`.
+
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{
+        mpsc::{self, Receiver},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use crossterm::{
+    event::{self, Event, KeyCode},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use strum::IntoEnumIterator;
+use tui::{backend::CrosstermBackend, Terminal};
+
+use crate::{
+    bribery::Bribe,
+    doctrines::Doctrine,
+    economy::Building,
+    screen::Screen,
+    state::{State, COLUMNS},
+    ui,
+};
+
+const DAY_DURATION: Duration = Duration::from_millis(500);
+const TICK_DURATION: Duration = Duration::from_millis(100);
+/// One building each
+const MAX_PLAYERS: usize = 4;
+/// Headlines per settlement sent with each snapshot
+const HEADLINES: usize = 20;
+
+/// Everything a player can do that changes the game, rather than just what's on their screen
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    Plus,
+    Minus,
+    Left,
+    Right,
+    Retarget,
+    Campaign,
+    Pay,
+    Travel,
+}
+
+impl Action {
+    pub fn apply(&self, state: &mut State) {
+        match self {
+            Self::Plus => state.plus(),
+            Self::Minus => state.minus(),
+            Self::Left => state.left(),
+            Self::Right => state.right(),
+            Self::Retarget => state.retarget(),
+            Self::Campaign => state.campaign(),
+            Self::Pay => state.pay(),
+            Self::Travel => state.travel(),
+        }
+    }
+}
+
+/// What a player has on their screen
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct View {
+    pub screen: Screen,
+    pub settlement: usize,
+    pub destination: usize,
+    pub control: (usize, usize),
+    /// Id of the operative under the cursor on the roster
+    pub operative: Option<usize>,
+    pub doctrine: usize,
+    pub bribe: usize,
+}
+
+impl View {
+    pub fn of(state: &State) -> Self {
+        Self {
+            screen: state.screen.clone(),
+            settlement: state.settlement,
+            destination: state.destination,
+            control: state.control,
+            operative: state
+                .nihilists()
+                .operatives
+                .get(state.selected)
+                .map(|o| o.id),
+            doctrine: state.doctrine,
+            bribe: state.bribe,
+        }
+    }
+
+    /// Checks that everything in the view is still there to be looked at
+    pub fn validate(&self, state: &State) -> Result<(), String> {
+        let Some(settlement) = state.world.settlements.get(self.settlement) else {
+            return Err(format!("No such settlement: {}", self.settlement));
+        };
+
+        let neighbours = state.world.neighbours(self.settlement).len();
+        if self.destination >= neighbours.max(1) {
+            return Err(format!("No such destination: {}", self.destination));
+        }
+
+        let (column, row) = self.control;
+        if column >= COLUMNS || row >= State::rows(column) {
+            return Err(format!("No such control: {:?}", self.control));
+        }
+
+        if let Some(id) = self.operative {
+            if !settlement.nihilists.operatives.iter().any(|o| o.id == id) {
+                return Err(format!("No such operative: {}", id));
+            }
+        }
+
+        if self.doctrine >= Doctrine::iter().count() {
+            return Err(format!("No such doctrine: {}", self.doctrine));
+        }
+
+        if self.bribe >= Bribe::iter().count() {
+            return Err(format!("No such bribe: {}", self.bribe));
+        }
+
+        Ok(())
+    }
+
+    /// Puts the view back on a state, keeping the cursor on something that's still there
+    pub fn show(&self, state: &mut State) {
+        state.screen = self.screen.clone();
+        state.settlement = self.settlement.min(state.world.settlements.len() - 1);
+        state.destination = self.destination;
+        state.control = self.control;
+        state.selected = self
+            .operative
+            .and_then(|id| state.nihilists().operatives.iter().position(|o| o.id == id))
+            .unwrap_or(0);
+        state.doctrine = self.doctrine;
+        state.bribe = self.bribe;
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Request {
+    pub action: Action,
+    #[serde(default)]
+    pub view: View,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Snapshot<S> {
+    pub owned: Vec<Building>,
+    pub state: S,
+}
+
+/// Why a request was turned down
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Refusal {
+    pub error: String,
+}
+
+impl Refusal {
+    fn send(stream: &mut TcpStream, error: String) {
+        // In one go, so it can't get mixed up with a snapshot
+        if let Ok(line) = serde_json::to_string(&Self { error }) {
+            let _ = stream.write_all(format!("{}\n", line).as_bytes());
+        }
+    }
+}
+
+/// The buildings dealt to one of a number of players
+fn deal(player: usize, players: usize) -> Vec<Building> {
+    Building::iter()
+        .enumerate()
+        .filter(|(i, _)| i % players.max(1) == player)
+        .map(|(_, b)| b)
+        .collect()
+}
+
+/// Players by the order they joined in, each with the stream to send them snapshots on
+type Players = Arc<Mutex<Vec<(usize, TcpStream)>>>;
+
+/// The co-op game's end of the socket, taking in the players' requests
+pub struct Server {
+    requests: Receiver<(usize, Request)>,
+    players: Players,
+    addr: SocketAddr,
+}
+
+impl Server {
+    /// Takes in players on the given address in the background, turning away any past the fourth
+    pub fn listen(addr: &str) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let players: Players = Arc::new(Mutex::new(vec![]));
+        let (sender, requests) = mpsc::channel();
+
+        let joined = players.clone();
+        std::thread::spawn(move || {
+            for (id, mut stream) in listener.incoming().map_while(Result::ok).enumerate() {
+                let mut players = joined.lock().unwrap();
+                if players.len() >= MAX_PLAYERS {
+                    Refusal::send(&mut stream, "The game is full".to_string());
+                    continue;
+                }
+
+                let (Ok(writer), Ok(mut replies)) = (stream.try_clone(), stream.try_clone()) else {
+                    continue;
+                };
+                players.push((id, writer));
+                drop(players);
+                println!("Player {} joined", id);
+
+                let sender = sender.clone();
+                let left = joined.clone();
+                std::thread::spawn(move || {
+                    for line in BufReader::new(stream).lines().map_while(Result::ok) {
+                        match serde_json::from_str::<Request>(&line) {
+                            Ok(request) => {
+                                if sender.send((id, request)).is_err() {
+                                    return;
+                                }
+                            }
+                            Err(error) => Refusal::send(&mut replies, error.to_string()),
+                        }
+                    }
+
+                    left.lock().unwrap().retain(|(i, _)| *i != id);
+                    println!("Player {} left", id);
+                });
+            }
+        });
+
+        Ok(Self {
+            requests,
+            players,
+            addr,
+        })
+    }
+
+    /// The address players connect to
+    pub fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Carries out whatever the players have asked for since last time, each with the buildings
+    /// dealt to them
+    pub fn serve(&mut self, state: &mut State) {
+        let requests: Vec<_> = self.requests.try_iter().collect();
+
+        for (id, request) in requests {
+            let mut players = self.players.lock().unwrap();
+            let count = players.len();
+            let Some(player) = players.iter().position(|(i, _)| *i == id) else {
+                continue;
+            };
+
+            if let Err(error) = request.view.validate(state) {
+                Refusal::send(&mut players[player].1, error);
+                continue;
+            }
+            drop(players);
+
+            state.owned = Some(deal(player, count));
+            request.view.show(state);
+            request.action.apply(state);
+            state.owned = None;
+        }
+    }
+
+    /// Sends every player the state of the game, dropping those who've hung up
+    pub fn broadcast(&self, state: &State) {
+        let Ok(mut state) = serde_json::to_value(state) else {
+            return;
+        };
+
+        // Only the latest headlines, or snapshots would grow without end
+        if let Some(settlements) = state
+            .pointer_mut("/world/settlements")
+            .and_then(Value::as_array_mut)
+        {
+            for settlement in settlements {
+                if let Some(headlines) = settlement
+                    .get_mut("headlines")
+                    .and_then(Value::as_array_mut)
+                {
+                    headlines.truncate(HEADLINES);
+                }
+            }
+        }
+
+        let mut players = self.players.lock().unwrap();
+        let count = players.len();
+
+        let mut player = 0;
+        players.retain_mut(|(_, stream)| {
+            let snapshot = Snapshot {
+                owned: deal(player, count),
+                state: &state,
+            };
+            player += 1;
+
+            serde_json::to_string(&snapshot)
+                .map(|line| stream.write_all(format!("{}\n", line).as_bytes()).is_ok())
+                .unwrap_or(false)
+        });
+    }
+}
+
+/// Runs the game for whoever connects to the given address, until the human race is no more
+pub fn serve(addr: &str, mut state: State) -> std::io::Result<()> {
+    let mut server = Server::listen(addr)?;
+
+    println!("Serving on {}", server.local_addr());
+    let mut last_tick = Instant::now();
+
+    loop {
+        server.serve(&mut state);
+
+        if last_tick.elapsed() >= DAY_DURATION {
+            state = state.next();
+            last_tick = Instant::now();
+
+            server.broadcast(&state);
+        }
+
+        if state.extinct() {
+            println!("The human race is extinct! 🎉");
+            return Ok(());
+        }
+
+        std::thread::sleep(TICK_DURATION);
+    }
+}
+
+/// Joins a co-op game, rendering whatever the server sends with the usual screens
+pub fn play(addr: &str) -> std::io::Result<()> {
+    let mut stream = TcpStream::connect(addr)?;
+    let reader = BufReader::new(stream.try_clone()?);
+    let (sender, snapshots) = mpsc::channel();
+
+    // Snapshots are parsed on this side, as the state's dice can't be sent across threads
+    std::thread::spawn(move || {
+        for line in reader.lines().map_while(Result::ok) {
+            if sender.send(line).is_err() {
+                return;
+            }
+        }
+    });
+
+    // Nothing to show until the server has sent the first snapshot
+    let mut first = None;
+    for line in snapshots.iter() {
+        if let Ok(snapshot) = serde_json::from_str::<Snapshot<State>>(&line) {
+            first = Some(snapshot);
+            break;
+        }
+        if let Ok(refusal) = serde_json::from_str::<Refusal>(&line) {
+            println!("{}", refusal.error);
+            return Ok(());
+        }
+    }
+    let Some(snapshot) = first else {
+        return Ok(());
+    };
+    let mut state = snapshot.state;
+    state.owned = Some(snapshot.owned);
+
+    enable_raw_mode()?;
+    let mut stdout = std::io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+
+    loop {
+        let mut should_quit = false;
+
+        if event::poll(Duration::from_millis(1))? {
+            if let Event::Key(key) = event::read()? {
+                let action = match key.code {
+                    KeyCode::Char('Q') | KeyCode::Char('q') => {
+                        should_quit = true;
+                        None
+                    }
+                    KeyCode::Char('+') => Some(Action::Plus),
+                    KeyCode::Char('-') => Some(Action::Minus),
+                    KeyCode::Char('T') | KeyCode::Char('t') => Some(Action::Retarget),
+                    KeyCode::Char('C') | KeyCode::Char('c') => Some(Action::Campaign),
+                    KeyCode::Char('$') => Some(Action::Pay),
+                    KeyCode::Char('M') | KeyCode::Char('m') => Some(Action::Travel),
+                    // Changing roles on the roster is up to the server, moving around isn't
+                    KeyCode::Left if state.screen == Screen::Roster => Some(Action::Left),
+                    KeyCode::Right if state.screen == Screen::Roster => Some(Action::Right),
+                    KeyCode::Left => {
+                        state.left();
+                        None
+                    }
+                    KeyCode::Right => {
+                        state.right();
+                        None
+                    }
+                    KeyCode::Up => {
+                        state.up();
+                        None
+                    }
+                    KeyCode::Down => {
+                        state.down();
+                        None
+                    }
+                    KeyCode::Tab => {
+                        state.switch_screen();
+                        None
+                    }
+                    _ => None,
+                };
+
+                if let Some(action) = action {
+                    let request = Request {
+                        action,
+                        view: View::of(&state),
+                    };
+                    writeln!(stream, "{}", serde_json::to_string(&request)?)?;
+                }
+            }
+        }
+
+        let mut hung_up = false;
+        loop {
+            match snapshots.try_recv() {
+                Ok(line) => {
+                    let Ok(snapshot) = serde_json::from_str::<Snapshot<State>>(&line) else {
+                        continue;
+                    };
+
+                    let view = View::of(&state);
+                    state = snapshot.state;
+                    view.show(&mut state);
+                    state.owned = Some(snapshot.owned);
+                }
+                Err(mpsc::TryRecvError::Empty) => break,
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    hung_up = true;
+                    break;
+                }
+            }
+        }
+
+        terminal.draw(|frame| ui::draw(frame, &state))?;
+
+        if should_quit || hung_up || state.extinct() {
+            disable_raw_mode()?;
+            execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+            terminal.show_cursor()?;
+
+            if state.extinct() {
+                println!("The human race is extinct! 🎉");
+            }
+
+            return Ok(());
+        }
+
+        std::thread::sleep(TICK_DURATION);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loopback;
+
+    #[test]
+    fn deals_every_building_once() {
+        for players in 1..=MAX_PLAYERS {
+            let mut dealt: Vec<Building> = (0..players).flat_map(|p| deal(p, players)).collect();
+            dealt.sort();
+
+            assert_eq!(dealt, Building::iter().collect::<Vec<_>>());
+            assert!((0..players).all(|p| !deal(p, players).is_empty()));
+        }
+    }
+
+    #[test]
+    fn rejects_views_of_nothing() {
+        let state = State::seeded(1);
+
+        assert!(View::of(&state).validate(&state).is_ok());
+
+        let views = [
+            View {
+                settlement: 99,
+                ..Default::default()
+            },
+            View {
+                control: (COLUMNS, 0),
+                ..Default::default()
+            },
+            View {
+                control: (1, 4),
+                ..Default::default()
+            },
+            View {
+                operative: Some(999),
+                ..Default::default()
+            },
+            View {
+                doctrine: 99,
+                ..Default::default()
+            },
+            View {
+                bribe: 99,
+                ..Default::default()
+            },
+            View {
+                destination: 99,
+                ..Default::default()
+            },
+        ];
+        for view in views {
+            assert!(view.validate(&state).is_err(), "{:?}", view);
+        }
+    }
+
+    /// Reads a line of JSON from the server, carrying out requests while waiting for it
+    fn reply(server: &mut Server, state: &mut State, reader: &mut impl BufRead) -> Value {
+        serde_json::from_str(&loopback::line(reader, || server.serve(state))).unwrap()
+    }
+
+    #[test]
+    fn serves_over_loopback() {
+        let mut state = State::seeded(1);
+        let mut server = Server::listen("127.0.0.1:0").unwrap();
+
+        let (mut player, mut reader) = loopback::connect(server.local_addr());
+
+        // Wait to be dealt in
+        loopback::until(|| !server.players.lock().unwrap().is_empty());
+
+        let target = state.nihilists().target.clone();
+        let request = Request {
+            action: Action::Retarget,
+            view: View::default(),
+        };
+        writeln!(player, "{}", serde_json::to_string(&request).unwrap()).unwrap();
+
+        loopback::until(|| {
+            server.serve(&mut state);
+            state.nihilists().target != target
+        });
+        assert_ne!(state.nihilists().target, target);
+
+        server.broadcast(&state);
+        let snapshot = reply(&mut server, &mut state, &mut reader);
+        assert_eq!(snapshot["owned"].as_array().unwrap().len(), MAX_PLAYERS);
+
+        let request = Request {
+            action: Action::Plus,
+            view: View {
+                control: (9, 9),
+                ..Default::default()
+            },
+        };
+        writeln!(player, "{}", serde_json::to_string(&request).unwrap()).unwrap();
+        assert!(reply(&mut server, &mut state, &mut reader)["error"].is_string());
+
+        writeln!(player, "{{\"action\": ").unwrap();
+        assert!(reply(&mut server, &mut state, &mut reader)["error"].is_string());
+    }
+
+    #[test]
+    fn turns_away_a_fifth_player() {
+        let server = Server::listen("127.0.0.1:0").unwrap();
+        let players: Vec<TcpStream> = (0..MAX_PLAYERS)
+            .map(|_| TcpStream::connect(server.local_addr()).unwrap())
+            .collect();
+
+        loopback::until(|| server.players.lock().unwrap().len() == players.len());
+
+        let fifth = TcpStream::connect(server.local_addr()).unwrap();
+        let mut line = String::new();
+        BufReader::new(fifth).read_line(&mut line).unwrap();
+        let refusal: Refusal = serde_json::from_str(&line).unwrap();
+        assert_eq!(refusal.error, "The game is full");
+    }
+}
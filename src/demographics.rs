@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+const CHILD_MATURITY: f32 = 0.25;
+const WORKER_AGEING: f32 = 0.05;
+
+/// The settlement's population split by age. Only workers produce anything.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Population {
+    pub children: usize,
+    pub workers: usize,
+    pub elders: usize,
+}
+
+impl Population {
+    pub fn total(&self) -> usize {
+        self.children + self.workers + self.elders
+    }
+
+    /// Daily food needs. Children and elders eat half as much as workers.
+    pub fn appetite(&self) -> usize {
+        self.workers + (self.children + self.elders).div_ceil(2)
+    }
+
+    /// Grows everyone a season older. Returns how many children came of age and how many
+    /// workers retired.
+    pub fn age(&mut self) -> (usize, usize) {
+        let matured = (self.children as f32 * CHILD_MATURITY).ceil() as usize;
+        let retired = (self.workers as f32 * WORKER_AGEING).ceil() as usize;
+
+        self.children -= matured;
+        self.workers += matured;
+        self.workers -= retired;
+        self.elders += retired;
+
+        (matured, retired)
+    }
+
+    /// Kills up to `n` people, the elderly and the young first. Returns how many died.
+    pub fn kill_vulnerable(&mut self, n: usize) -> usize {
+        let mut left = n;
+
+        for group in [&mut self.elders, &mut self.children, &mut self.workers] {
+            let dead = std::cmp::min(left, *group);
+            *group -= dead;
+            left -= dead;
+        }
+
+        n - left
+    }
+
+    /// Up to `n` people leave the settlement, workers first, then children, then elders. Returns
+    /// how many left.
+    pub fn emigrate(&mut self, n: usize) -> usize {
+        let mut left = n;
+
+        for group in [&mut self.workers, &mut self.children, &mut self.elders] {
+            let gone = std::cmp::min(left, *group);
+            *group -= gone;
+            left -= gone;
+        }
+
+        n - left
+    }
+
+    /// Kills up to `n` workers. Returns how many died.
+    pub fn kill_workers(&mut self, n: usize) -> usize {
+        let dead = std::cmp::min(n, self.workers);
+        self.workers -= dead;
+        dead
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn workers_emigrate_first() {
+        let mut population = Population {
+            children: 4,
+            workers: 10,
+            elders: 3,
+        };
+
+        assert_eq!(population.emigrate(12), 12);
+        assert_eq!(population.workers, 0);
+        assert_eq!(population.children, 2);
+        assert_eq!(population.elders, 3);
+    }
+
+    #[test]
+    fn cannot_emigrate_more_than_everyone() {
+        let mut population = Population {
+            children: 1,
+            workers: 2,
+            elders: 3,
+        };
+
+        assert_eq!(population.emigrate(10), 6);
+        assert_eq!(population.total(), 0);
+    }
+}
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, EnumIter};
+
+/// What the nihilists have learned over the years. Every doctrine is bought once, either with
+/// efficiency points or with rubles from the treasury, and some build on others.
+#[derive(Clone, Debug, PartialEq, Eq, EnumIter, AsRefStr, Serialize, Deserialize)]
+pub enum Doctrine {
+    Rhetoric,
+    Oratory,
+    Explosives,
+    Dynamite,
+    ForgedLedgers,
+    Counterfeiting,
+    SafeHouses,
+    EscapeRoutes,
+}
+
+impl Doctrine {
+    /// The doctrine that has to be learned first, if any
+    pub fn requires(&self) -> Option<Self> {
+        match self {
+            Self::Oratory => Some(Self::Rhetoric),
+            Self::Dynamite => Some(Self::Explosives),
+            Self::Counterfeiting => Some(Self::ForgedLedgers),
+            Self::EscapeRoutes => Some(Self::SafeHouses),
+            _ => None,
+        }
+    }
+
+    /// Price in efficiency points
+    pub fn points(&self) -> f32 {
+        match self.requires() {
+            None => 0.1,
+            Some(_) => 0.25,
+        }
+    }
+
+    /// Price in rubles
+    pub fn rubles(&self) -> usize {
+        match self.requires() {
+            None => 50,
+            Some(_) => 150,
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Rhetoric => "Agitators are 25% more persuasive",
+            Self::Oratory => "Agitators win over recruits of their own",
+            Self::Explosives => "Saboteurs are 25% more likely to strike",
+            Self::Dynamite => "Fires destroy twice as much storage",
+            Self::ForgedLedgers => "Embezzlers skim 25% more",
+            Self::Counterfeiting => "Embezzled goods fetch twice the rubles",
+            Self::SafeHouses => "Confessions and raids expose half as many comrades",
+            Self::EscapeRoutes => "Hitmen are half as likely to be caught",
+        }
+    }
+}
+
+impl std::fmt::Display for Doctrine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Rhetoric => write!(f, "📣 Rhetoric"),
+            Self::Oratory => write!(f, "🎤 Oratory"),
+            Self::Explosives => write!(f, "🧨 Explosives"),
+            Self::Dynamite => write!(f, "💥 Dynamite"),
+            Self::ForgedLedgers => write!(f, "📒 Forged ledgers"),
+            Self::Counterfeiting => write!(f, "💵 Counterfeiting"),
+            Self::SafeHouses => write!(f, "🏚️ Safe houses"),
+            Self::EscapeRoutes => write!(f, "🚪 Escape routes"),
+        }
+    }
+}
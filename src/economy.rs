@@ -1,46 +1,134 @@
-use std::fmt::Display;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{collections::HashMap, fmt::Display};
 
+use serde::{Deserialize, Serialize};
 use strum::{AsRefStr, EnumIter, IntoEnumIterator};
 
-use crate::nihilists::Nihilists;
+use crate::{
+    bribery::Bribe,
+    chronicle::YearStats,
+    demographics::Population,
+    events::EVENTS,
+    governor::{Governor, SECURITY_SUSPICION},
+    hitmen::{Outcome, Target},
+    nihilists::Nihilists,
+    operatives::Role,
+    projects::{Construction, Project, LONGHOUSE_HOUSING},
+    trials::{Verdict, TRIAL_DAYS},
+    weather::Weather,
+};
 
 const FOOD_TO_BABIES: f32 = 0.05;
 const FOOD_TO_DEATHS: f32 = 0.35;
-const EFFICIENCY_STEP: f32 = 0.001;
-const STORAGE_STEP: usize = 1;
-const HOUSING_STEP: usize = 1;
+const WOOD_TO_DEATHS: f32 = 0.25;
+const ELDER_MORTALITY: f32 = 0.01;
+const INFECTION_LIKELIHOOD: f32 = 0.0005;
+const CROWDED_INFECTION: f32 = 0.002;
+const HUNGRY_INFECTION: f32 = 0.005;
+const WINTER_INFECTION: f32 = 0.002;
+const CONTAGION: f32 = 0.05;
+const DISEASE_MORTALITY: f32 = 0.02;
+const RECOVERY_LIKELIHOOD: f32 = 0.15;
+const MORALE_DRIFT: f32 = 0.2;
+const HUNGER_MORALE: f32 = 0.5;
+const HIT_MORALE: f32 = 0.1;
+const GOVERNOR_MORALE: f32 = 0.5;
+const GOVERNOR_VACANCY: usize = 20;
+const FOREMAN_DAYS: usize = 10;
+const FOREMAN_MODIFIER: f32 = 0.5;
+const FRIGHT_DAYS: usize = 5;
+const FRIGHT_MODIFIER: f32 = 0.75;
+const IMMIGRATION_MORALE: f32 = 0.7;
+const IMMIGRATION_LIKELIHOOD: f32 = 0.1;
+const EMIGRATION_MORALE: f32 = 0.4;
+const EMIGRATION_STEP: f32 = 0.1;
+const FORGE_EFFICIENCY: f32 = 0.01;
+const STORAGE_UPGRADE: usize = 25;
+const STORAGE_SABOTAGE: usize = 10;
+const MIN_STORAGE: usize = 50;
+const CORRUPTION_DRIFT: f32 = 0.001;
+const BRIBE_CORRUPTION: f32 = 0.02;
+const BRIBE_ACCEPTANCE: f32 = 0.5;
+const POLICE_SUSPICION: f32 = 0.2;
+const REFUSED_SUSPICION: f32 = 0.1;
+const MAGISTRATE_DELAY: usize = 7;
+const RAID_SHARE: f32 = 0.1;
+const INVESTIGATION_DAYS: usize = 7;
+const INVESTIGATION_SUCCESS: f32 = 0.6;
+const SPECIALISATION_BONUS: f32 = 1.5;
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Economy {
     pub food: usize,
     pub wood: usize,
     pub stone: usize,
     pub iron: usize,
-    pub storage: usize,
+    pub storage: HashMap<Resource, usize>,
 
     pub efficiency: f32,
 
-    pub population: usize,
+    pub population: Population,
     pub population_cap: usize,
+    pub infected: usize,
+
+    /// Days each building still has to go without a foreman
+    pub leaderless: HashMap<Building, usize>,
+    /// Days the workers of each building still keep their heads down after one of them was killed
+    pub frightened: HashMap<Building, usize>,
+
+    pub morale: f32,
+
+    pub spoiled: usize,
+    pub burned: usize,
+
+    pub constructions: Vec<Construction>,
+
+    /// How easily officials can be bought, between 0 and 1
+    pub corruption: f32,
+    /// Days left on every open investigation into the killings
+    pub investigations: Vec<usize>,
+
+    pub weather: Weather,
+    pub forecast: Weather,
+
+    pub year: YearStats,
+    pub years: Vec<YearStats>,
 
     pub day: usize,
+    /// How far ahead of the day the seasons are, for settlements on other latitudes
+    pub offset: usize,
+    /// The building that does better here than anywhere else
+    pub specialisation: Option<Building>,
+
+    pub governor: Governor,
+
+    /// Only the host rolls the dice
+    #[serde(skip, default = "StdRng::from_entropy")]
+    rng: StdRng,
 }
 
 impl Display for Economy {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "-------------------------")?;
-        writeln!(f, "Day {} ({})", self.day, Season::from(self.day).as_ref())?;
+        writeln!(
+            f,
+            "Day {} ({})",
+            self.day,
+            Season::from(self.calendar()).as_ref()
+        )?;
         writeln!(f, "-------------------------")?;
         writeln!(f, "🍖 {:>5}", self.food)?;
         writeln!(f, "🪵 {:>5}", self.wood)?;
         writeln!(f, "🪨 {:>5}", self.stone)?;
         writeln!(f, "🪙 {:>5}", self.iron)?;
         writeln!(f)?;
-        writeln!(f, "📦 {:>5}", self.storage)?;
+        for resource in Resource::iter() {
+            writeln!(f, "📦 {:>5}", self.storage.get(&resource).unwrap())?;
+        }
         writeln!(f)?;
         writeln!(f, "🏭 {:>4.1}%", (self.efficiency - 1.0) * 100.0)?;
         writeln!(f)?;
-        writeln!(f, "👨‍👩‍👧‍👦 {:>5}", self.population)?;
+        writeln!(f, "👨‍👩‍👧‍👦 {:>5}", self.population.total())?;
         writeln!(f, "🛖 {:>5}", self.population_cap)?;
         writeln!(f, "-------------------------\n\n\n")
     }
@@ -53,73 +141,203 @@ impl Default for Economy {
             wood: 0,
             stone: 0,
             iron: 0,
-            storage: 200,
+            storage: Resource::iter().map(|r| (r, 200)).collect(),
 
             efficiency: 1.0,
 
-            population: 10,
+            population: Population {
+                children: 2,
+                workers: 7,
+                elders: 1,
+            },
             population_cap: 20,
+            infected: 0,
+
+            leaderless: Building::iter().map(|b| (b, 0)).collect(),
+            frightened: Building::iter().map(|b| (b, 0)).collect(),
+
+            morale: 1.0,
+
+            spoiled: 0,
+            burned: 0,
+
+            constructions: vec![],
+
+            corruption: 0.1,
+            investigations: vec![],
+
+            weather: Weather::Mild,
+            forecast: Weather::Mild,
+
+            year: YearStats::new(1),
+            years: vec![],
 
             day: 0,
+            offset: 0,
+            specialisation: None,
+
+            governor: Governor::default(),
+
+            rng: StdRng::from_entropy(),
         }
     }
 }
 
 impl Economy {
+    /// An economy whose weather is driven by the given seed
+    pub fn seeded(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+            ..Default::default()
+        }
+    }
+
+    /// The economy as it stands `days` into its first year, having had the seasons so far to stock
+    /// up. Its day count starts from there.
+    pub fn settled(mut self, days: usize) -> Self {
+        let mut nobody = Nihilists::with_operatives(0);
+        for _ in 0..days {
+            self = self.next(&mut nobody).0;
+        }
+
+        Self {
+            day: 0,
+            offset: self.offset + days,
+            year: YearStats::new(1),
+            years: vec![],
+            ..self
+        }
+    }
+
+    /// The day as far as the seasons are concerned
+    pub fn calendar(&self) -> usize {
+        self.day + self.offset
+    }
+
+    pub fn foreman_modifier(&self, building: &Building) -> f32 {
+        if *self.leaderless.get(building).unwrap() > 0 {
+            FOREMAN_MODIFIER
+        } else {
+            1.0
+        }
+    }
+
+    pub fn fright_modifier(&self, building: &Building) -> f32 {
+        if *self.frightened.get(building).unwrap() > 0 {
+            FRIGHT_MODIFIER
+        } else {
+            1.0
+        }
+    }
+
     pub fn extinct(&self) -> bool {
-        self.population < 2
+        self.population.total() < 2
+    }
+
+    /// How much of a resource is in store
+    pub fn stock(&self, resource: &Resource) -> usize {
+        match resource {
+            Resource::Food => self.food,
+            Resource::Wood => self.wood,
+            Resource::Stone => self.stone,
+            Resource::Iron => self.iron,
+        }
+    }
+
+    pub fn stock_mut(&mut self, resource: &Resource) -> &mut usize {
+        match resource {
+            Resource::Food => &mut self.food,
+            Resource::Wood => &mut self.wood,
+            Resource::Stone => &mut self.stone,
+            Resource::Iron => &mut self.iron,
+        }
+    }
+
+    /// How much of a resource fits in its store
+    pub fn capacity(&self, resource: &Resource) -> usize {
+        *self.storage.get(resource).unwrap()
     }
 
     pub fn next(&self, nils: &mut Nihilists) -> (Self, Vec<String>) {
         // Headlines
         let mut headlines = vec![];
 
-        // Get the standard production per citizen based on the season
-        let production = Season::from(self.day).production();
+        let season = Season::from(self.calendar());
 
-        let population_f = self.population as f32;
+        // Yesterday's forecast is today's weather
+        let mut rng = self.rng.clone();
+        let weather = self.forecast.clone();
+        let forecast = Weather::roll(&Season::from(self.calendar() + 1), &mut rng);
+        if weather != self.weather {
+            headlines.push(format!("[Day {:>3}] {}", self.day, weather.headline()));
+        }
 
-        // Multiply it by the population and efficiency modifier and embezzlement modifier
+        // Get the standard production per citizen based on the season and the weather
+        let mut production = season.production();
+        for (production, modifier) in production.iter_mut().zip(weather.production()) {
+            *production *= modifier;
+        }
+        for (building, production) in Building::iter().zip(production.iter_mut()) {
+            if self.specialisation.as_ref() == Some(&building) {
+                *production *= SPECIALISATION_BONUS;
+            }
+            *production *= self.governor.labour(&building);
+        }
+
+        // The sick don't show up for work, and the guards don't work at all
+        let healthy = 1.0 - self.infected as f32 / self.population.total().max(1) as f32;
+        let population_f = self.population.workers as f32 * healthy * self.governor.garrison();
+
+        // Multiply it by the workforce and efficiency modifier and embezzlement modifier
         let mut food = (production[0]
             * population_f
             * self.efficiency
-            * nils.agitator_modifier(&Building::Hunting)) as usize;
+            * nils.agitator_modifier(&Building::Hunting)
+            * self.foreman_modifier(&Building::Hunting)
+            * self.fright_modifier(&Building::Hunting)) as usize;
         let mut wood = (production[1]
             * population_f
             * self.efficiency
-            * nils.agitator_modifier(&Building::Lumberyard)) as usize;
+            * nils.agitator_modifier(&Building::Lumberyard)
+            * self.foreman_modifier(&Building::Lumberyard)
+            * self.fright_modifier(&Building::Lumberyard)) as usize;
         let mut stone = (production[2]
             * population_f
             * self.efficiency
-            * nils.agitator_modifier(&Building::Quarry)) as usize;
+            * nils.agitator_modifier(&Building::Quarry)
+            * self.foreman_modifier(&Building::Quarry)
+            * self.fright_modifier(&Building::Quarry)) as usize;
         let mut iron = (production[3]
             * population_f
             * self.efficiency
-            * nils.agitator_modifier(&Building::Mine)) as usize;
+            * nils.agitator_modifier(&Building::Mine)
+            * self.foreman_modifier(&Building::Mine)
+            * self.fright_modifier(&Building::Mine)) as usize;
 
-        // Sabotage
-        if nils.sabotaged(&Building::Hunting) {
+        // Sabotage, rolled once per building
+        let sabotaged: Vec<Building> = Building::iter().filter(|b| nils.sabotaged(b)).collect();
+        if sabotaged.contains(&Building::Hunting) {
             headlines.push(format!(
                 "[Day {:>3}] Hunting accident involving a bad arrows suspends meat production!",
                 self.day
             ));
             food = 0;
         }
-        if nils.sabotaged(&Building::Lumberyard) {
+        if sabotaged.contains(&Building::Lumberyard) {
             headlines.push(format!(
                 "[Day {:>3}] Lumberyard out of commission - all axes mysteriously blunt!",
                 self.day
             ));
             wood = 0;
         }
-        if nils.sabotaged(&Building::Quarry) {
+        if sabotaged.contains(&Building::Quarry) {
             headlines.push(format!(
                 "[Day {:>3}] Quarry flooded! Investigation into faulty pressure regulators",
                 self.day
             ));
             stone = 0;
         }
-        if nils.sabotaged(&Building::Mine) {
+        if sabotaged.contains(&Building::Mine) {
             headlines.push(format!(
                 "[Day {:>3}] Cavein at the mine! Suspicious explosion at the lower levels.",
                 self.day
@@ -128,6 +346,27 @@ impl Economy {
             iron = 0;
         }
 
+        // Sabotage also damages the stores
+        let mut storage = self.storage.clone();
+        for building in &sabotaged {
+            let capacity = storage.get_mut(&building.resource()).unwrap();
+            *capacity = std::cmp::max(
+                capacity.saturating_sub(STORAGE_SABOTAGE * nils.blast()),
+                MIN_STORAGE,
+            );
+
+            headlines.push(format!(
+                "[Day {:>3}] Fire at the {}! Part of the stores lost.",
+                self.day,
+                building.resource().store()
+            ));
+        }
+
+        let mut year = self.year.clone();
+        for (total, produced) in year.production.iter_mut().zip([food, wood, stone, iron]) {
+            *total += produced;
+        }
+
         // Add the previous stockpiles
         food += self.food;
         wood += self.wood;
@@ -135,81 +374,568 @@ impl Economy {
         iron += self.iron;
 
         // Embezzle
+        let before = food + wood + stone + iron;
         food = (food as f32 * nils.embezzlement(&Building::Hunting)) as usize;
         wood = (wood as f32 * nils.embezzlement(&Building::Lumberyard)) as usize;
         stone = (stone as f32 * nils.embezzlement(&Building::Quarry)) as usize;
         iron = (iron as f32 * nils.embezzlement(&Building::Mine)) as usize;
+        nils.stash(before.saturating_sub(food + wood + stone + iron));
+
+        // Guards keep their eyes open
+        nils.suspicion =
+            (nils.suspicion + self.governor.security as f32 * SECURITY_SUSPICION).min(1.0);
+
+        // Bribes, if the officials can be bought
+        let mut corruption = (self.corruption + CORRUPTION_DRIFT).min(1.0);
+        let mut investigations = self.investigations.clone();
+        for bribe in std::mem::take(&mut nils.bribes) {
+            if rng.gen::<f32>() >= BRIBE_ACCEPTANCE + corruption {
+                nils.suspicion = (nils.suspicion + REFUSED_SUSPICION).min(1.0);
+                headlines.push(format!(
+                    "[Day {:>3}] Official refuses a bribe and reports it to the governor!",
+                    self.day
+                ));
+                continue;
+            }
+
+            corruption = (corruption + BRIBE_CORRUPTION).min(1.0);
+            match bribe {
+                Bribe::Police => nils.suspicion = (nils.suspicion - POLICE_SUSPICION).max(0.0),
+                Bribe::Magistrate => investigations
+                    .iter_mut()
+                    .for_each(|days| *days += MAGISTRATE_DELAY),
+                Bribe::Quartermaster => {
+                    let mut raided = 0;
+                    for stockpile in [&mut food, &mut wood, &mut stone, &mut iron] {
+                        let stolen = (*stockpile as f32 * RAID_SHARE) as usize;
+                        *stockpile -= stolen;
+                        raided += stolen;
+                    }
+                    nils.stash(raided);
+                }
+            }
+
+            headlines.push(format!("[Day {:>3}] {}", self.day, bribe.headline()));
+        }
+
+        // Spoilage
+        let spoiled = (self.food as f32 * season.spoilage()).ceil() as usize;
+        food = food.saturating_sub(spoiled);
+        if spoiled >= 10 {
+            headlines.push(format!(
+                "[Day {:>3}] Rats in the granary! {} food gone to waste.",
+                self.day,
+                nils.rumour(spoiled, Some(&Building::Hunting))
+            ));
+        }
 
         // Feed the masses
-        let mut population = if food >= self.population {
+        let mut population = self.population.clone();
+        let hungry = food < population.appetite();
+        if !hungry {
             // Feed the current population
-            food -= self.population;
+            food -= population.appetite();
 
             // Babies!
-            let space = self.population_cap - self.population;
+            let space = self.population_cap.saturating_sub(population.total());
             let births = std::cmp::min(space, (food as f32 * FOOD_TO_BABIES) as usize);
             food -= births;
-
-            self.population + births
+            population.children += births;
+            year.births += births;
         } else {
-            let missing_food = self.population - food;
+            let missing_food = population.appetite() - food;
             food = 0;
-            self.population - (missing_food as f32 * FOOD_TO_DEATHS).ceil() as usize
+            let starved = (missing_food as f32 * FOOD_TO_DEATHS).ceil() as usize;
+            year.deaths += population.kill_vulnerable(starved);
+        }
+
+        // Old age
+        let natural = (0..population.elders)
+            .filter(|_| rng.gen::<f32>() < ELDER_MORTALITY)
+            .count();
+        population.elders -= natural;
+        year.deaths += natural;
+        if natural > 0 {
+            headlines.push(format!(
+                "[Day {:>3}] {} of our elders passed away peacefully in their sleep.",
+                self.day,
+                nils.rumour(natural, None)
+            ));
+        }
+
+        // Disease
+        let crowded = population.total() * 10 >= self.population_cap * 9;
+        let mut infection_likelihood = INFECTION_LIKELIHOOD;
+        if crowded {
+            infection_likelihood += CROWDED_INFECTION;
+        }
+        if hungry {
+            infection_likelihood += HUNGRY_INFECTION;
+        }
+        if season == Season::Winter {
+            infection_likelihood += WINTER_INFECTION;
+        }
+        let contagion = CONTAGION * population.total() as f32 / self.population_cap.max(1) as f32;
+
+        let mut infected = std::cmp::min(self.infected, population.total());
+        let healthy = population.total() - infected;
+        let mut new_infections = (0..healthy)
+            .filter(|_| rng.gen::<f32>() < infection_likelihood)
+            .count()
+            + (0..infected)
+                .filter(|_| rng.gen::<f32>() < contagion)
+                .count();
+
+        for building in Building::iter() {
+            let (seeded, lost) = nils.infect(&building);
+            new_infections += seeded;
+
+            if seeded > 0 {
+                headlines.push(format!(
+                    "[Day {:>3}] Fever breaks out among the workers of the {}!",
+                    self.day, building
+                ));
+            }
+            if lost > 0 {
+                headlines.push(format!(
+                    "[Day {:>3}] {} strangers found dead of fever near the {}.",
+                    self.day, lost, building
+                ));
+            }
+        }
+
+        if infected == 0 && new_infections > 0 {
+            headlines.push(format!(
+                "[Day {:>3}] A strange illness is going around. Physicians urge calm.",
+                self.day
+            ));
+        }
+        infected += std::cmp::min(new_infections, healthy);
+
+        let succumbed = (0..infected)
+            .filter(|_| rng.gen::<f32>() < DISEASE_MORTALITY)
+            .count();
+        let succumbed = population.kill_vulnerable(succumbed);
+        infected -= succumbed;
+        year.deaths += succumbed;
+        if succumbed > 0 {
+            headlines.push(format!(
+                "[Day {:>3}] {} succumb to the fever.",
+                self.day,
+                nils.rumour(succumbed, None)
+            ));
+        }
+
+        let recovered = (0..infected)
+            .filter(|_| rng.gen::<f32>() < RECOVERY_LIKELIHOOD)
+            .count();
+        infected -= recovered;
+
+        // Hitmen
+        let mut leaderless: HashMap<Building, usize> = self
+            .leaderless
+            .iter()
+            .map(|(b, days)| (b.clone(), days.saturating_sub(1)))
+            .collect();
+        let mut frightened: HashMap<Building, usize> = self
+            .frightened
+            .iter()
+            .map(|(b, days)| (b.clone(), days.saturating_sub(1)))
+            .collect();
+        let (mut governor, arrived) = self.governor.next();
+        if arrived {
+            headlines.push(format!(
+                "[Day {:>3}] A new governor arrives from Petersburg to restore order.",
+                self.day
+            ));
+        }
+        let mut efficiency = self.efficiency;
+        let mut hits = 0;
+        let mut shock = 0.0;
+        // There's nobody to kill in an empty seat, so the hitmen settle for anyone
+        let target = match &nils.target {
+            Target::Governor if governor.vacant() => Target::Citizen,
+            Target::Foreman(building) if leaderless[building] > 0 => Target::Citizen,
+            target => target.clone(),
         };
+        for hit in nils.hit(&target) {
+            if hit.outcome != Outcome::Success {
+                headlines.push(format!(
+                    "[Day {:>3}] {}",
+                    self.day,
+                    match hit.outcome {
+                        Outcome::Escaped =>
+                            format!("Attack on {} foiled, {} escapes!", hit.target, hit.name),
+                        Outcome::Killed => format!(
+                            "{}, would-be assassin of {}, shot dead by guards.",
+                            hit.name, hit.target
+                        ),
+                        _ => format!(
+                            "{} captured after failed attempt on {}!",
+                            hit.name, hit.target
+                        ),
+                    }
+                ));
+
+                if hit.exposed > 0 {
+                    headlines.push(format!(
+                        "[Day {:>3}] Interrogation of {} leads to {} more arrests!",
+                        self.day, hit.name, hit.exposed
+                    ));
+                }
 
-        // Hitmen TODO: Headline
-        let hits = nils.hit();
-        population = population.saturating_sub(hits);
+                continue;
+            }
+
+            if population.kill_workers(1) == 0 {
+                continue;
+            }
+            hits += 1;
+
+            match &hit.target {
+                Target::Citizen => {}
+                Target::Foreman(building) => {
+                    *leaderless.get_mut(building).unwrap() = FOREMAN_DAYS;
+                    headlines.push(format!(
+                        "[Day {:>3}] The foreman of the {} found dead! Work slows to a crawl.",
+                        self.day, building
+                    ));
+                }
+                Target::Workers(building) => {
+                    *frightened.get_mut(building).unwrap() = FRIGHT_DAYS;
+                    headlines.push(format!(
+                        "[Day {:>3}] Worker murdered on the way to the {}. Their mates fear they're next.",
+                        self.day, building
+                    ));
+                }
+                Target::Guard => headlines.push(format!(
+                    "[Day {:>3}] Guard found stabbed on patrol. Night watch in disarray.",
+                    self.day
+                )),
+                Target::Governor => {
+                    shock += GOVERNOR_MORALE;
+                    governor.assassinate(GOVERNOR_VACANCY);
+                    headlines.push(format!(
+                        "[Day {:>3}] GOVERNOR ASSASSINATED! Chaos in the streets, nobody in charge.",
+                        self.day
+                    ));
+                }
+            }
+        }
+        year.deaths += hits;
         if hits > 0 {
             headlines.push(format!(
                 "[Day {:>3}] {} found dead this morning. Authorities launch investigation.",
                 self.day, hits
-            ))
+            ));
+            investigations.push(INVESTIGATION_DAYS);
         }
 
-        // Increase efficiency
-        let mut efficiency = self.efficiency;
-        if iron > 0 {
-            efficiency += EFFICIENCY_STEP;
-            iron -= 1;
+        // Investigations run their course, unless the investigators can be bought
+        let mut open = vec![];
+        for days in investigations {
+            if days > 1 {
+                open.push(days - 1);
+                continue;
+            }
+
+            if rng.gen::<f32>() >= INVESTIGATION_SUCCESS * (1.0 - corruption) {
+                headlines.push(format!(
+                    "[Day {:>3}] Investigation into the killings quietly dropped.",
+                    self.day
+                ));
+                continue;
+            }
+
+            match nils.arrest(&Role::Hitman) {
+                Some((name, exposed)) => {
+                    headlines.push(format!(
+                        "[Day {:>3}] Investigation closes in! {} arrested for murder.",
+                        self.day, name
+                    ));
+                    if exposed > 0 {
+                        headlines.push(format!(
+                            "[Day {:>3}] Interrogation of {} leads to {} more arrests!",
+                            self.day, name, exposed
+                        ));
+                    }
+                }
+                None => headlines.push(format!(
+                    "[Day {:>3}] Investigation ends with the arrest of an innocent citizen.",
+                    self.day
+                )),
+            }
+        }
+        let investigations = open;
+
+        // Trials of captured comrades
+        for mut trial in std::mem::take(&mut nils.trials) {
+            if trial.days_left == TRIAL_DAYS {
+                headlines.push(format!(
+                    "[Day {:>3}] Trial of {} on charges of {} opens.",
+                    self.day,
+                    trial.names(),
+                    trial.charge
+                ));
+            }
+
+            trial.days_left -= 1;
+            if trial.days_left == TRIAL_DAYS / 2 {
+                headlines.push(format!(
+                    "[Day {:>3}] Uproar in court. {}: \"We do not recognise this court!\"",
+                    self.day,
+                    trial.names()
+                ));
+            }
+            if trial.days_left > 0 {
+                nils.trials.push(trial);
+                continue;
+            }
+
+            let names = trial.names();
+            match trial.verdict(corruption, &mut rng) {
+                Verdict::Acquitted => {
+                    headlines.push(format!(
+                        "[Day {:>3}] {} acquitted! Jurors suspected of taking bribes.",
+                        self.day, names
+                    ));
+                    nils.release(trial.defendants);
+                }
+                Verdict::Exiled => headlines.push(format!(
+                    "[Day {:>3}] {} sentenced to hard labour in Siberia.",
+                    self.day, names
+                )),
+                Verdict::Executed => {
+                    headlines.push(format!(
+                        "[Day {:>3}] {} hanged at dawn. Crowds gather in silence.",
+                        self.day, names
+                    ));
+                    nils.martyr(trial.defendants.len());
+                }
+            }
         }
 
-        // Increase storage size
-        let mut storage_size = self.storage;
-        if stone > 0 {
-            storage_size += STORAGE_STEP;
-            stone -= 1;
+        // Informants tip off the police, unless the counterspies get to them first
+        for name in nils.unmask() {
+            headlines.push(format!(
+                "[Day {:>3}] Body of {}, a police informant, fished out of the river.",
+                self.day, name
+            ));
+        }
+        for arrested in nils.inform() {
+            headlines.push(format!(
+                "[Day {:>3}] Police raid on a tip-off! {} suspected nihilists arrested.",
+                self.day, arrested
+            ));
+        }
+
+        // Keep warm. Even a handful of people need a log on the fire.
+        let heating = (season.heating() * population.total() as f32).ceil() as usize;
+        let burned = std::cmp::min(heating, wood);
+        wood -= burned;
+        if burned < heating {
+            let frozen = ((heating - burned) as f32 * WOOD_TO_DEATHS).ceil() as usize;
+            let frozen = population.kill_vulnerable(frozen);
+            year.deaths += frozen;
+            headlines.push(format!(
+                "[Day {:>3}] Firewood runs out! {} freeze to death in their homes.",
+                self.day,
+                nils.rumour(frozen, None)
+            ));
+        }
+
+        // Morale drifts towards how people feel about today
+        let mut target = 1.0 - nils.discontent() - hits as f32 * HIT_MORALE - shock;
+        if hungry {
+            target -= HUNGER_MORALE;
+        }
+        let morale =
+            (self.morale + (target.clamp(0.0, 1.0) - self.morale) * MORALE_DRIFT).clamp(0.0, 1.0);
+
+        // Migration
+        let space = self.population_cap.saturating_sub(population.total());
+        if morale > IMMIGRATION_MORALE
+            && space > 0
+            && food >= population.appetite() * 5
+            && rng.gen::<f32>() < IMMIGRATION_LIKELIHOOD
+        {
+            let arrivals = rng.gen_range(1..=std::cmp::min(space, 3));
+            population.workers += arrivals;
+            year.immigrants += arrivals;
+            headlines.push(format!(
+                "[Day {:>3}] Word of plenty spreads, {} settlers arrive.",
+                self.day,
+                nils.rumour(arrivals, None)
+            ));
+        } else if morale < EMIGRATION_MORALE {
+            let likelihood = (EMIGRATION_MORALE - morale) * EMIGRATION_STEP;
+            let leaving = (0..population.total())
+                .filter(|_| rng.gen::<f32>() < likelihood)
+                .count();
+            let departures = population.emigrate(leaving);
+            year.emigrants += departures;
+            if departures > 0 {
+                headlines.push(format!(
+                    "[Day {:>3}] {} pack their belongings and leave in search of a better life.",
+                    self.day,
+                    nils.rumour(departures, None)
+                ));
+            }
         }
 
-        // Increase population_cap
         let mut population_cap = self.population_cap;
-        if wood > 0 {
-            population_cap += HOUSING_STEP;
-            wood -= 1;
+
+        // Progress ongoing constructions
+        let mut constructions = vec![];
+        for mut construction in self.constructions.iter().cloned() {
+            construction.days_left -= 1;
+
+            if construction.days_left > 0 {
+                constructions.push(construction);
+                continue;
+            }
+
+            match &construction.project {
+                Project::Longhouse => population_cap += LONGHOUSE_HOUSING,
+                Project::Forge => efficiency += FORGE_EFFICIENCY,
+                project => {
+                    *storage.get_mut(&project.expands().unwrap()).unwrap() += STORAGE_UPGRADE
+                }
+            }
+
+            headlines.push(format!(
+                "[Day {:>3}] {}",
+                self.day,
+                construction.project.headline()
+            ));
+        }
+
+        // Start new constructions with whatever we can afford, the governor's pick first
+        let investment = governor.investment.clone();
+        let projects = investment
+            .iter()
+            .cloned()
+            .chain(Project::iter().filter(|p| Some(p) != investment.as_ref()));
+        for project in projects {
+            if constructions.iter().any(|c| c.project == project) {
+                continue;
+            }
+
+            // Only expand stores that are filling up, unless the governor says otherwise
+            let invested = investment.as_ref() == Some(&project);
+            if let Some(store) = project.expands().filter(|_| !invested) {
+                let stocked = match store {
+                    Resource::Food => food,
+                    Resource::Wood => wood,
+                    Resource::Stone => stone,
+                    Resource::Iron => iron,
+                };
+
+                if stocked * 4 < *storage.get(&store).unwrap() * 3 {
+                    continue;
+                }
+            }
+
+            let (resource, cost) = project.cost();
+            let stockpile = match resource {
+                Resource::Wood => &mut wood,
+                Resource::Stone => &mut stone,
+                Resource::Iron => &mut iron,
+                Resource::Food => &mut food,
+            };
+
+            // Hold back enough firewood to get through the winter
+            let reserve = match resource {
+                Resource::Wood => {
+                    (Season::Winter.heating() * population.total() as f32).ceil() as usize
+                        * Season::Winter.length()
+                }
+                _ => 0,
+            };
+
+            if *stockpile >= cost + reserve {
+                *stockpile -= cost;
+                constructions.push(Construction::new(project));
+            }
+        }
+
+        // Whoever died or left today might have been sick
+        let infected = std::cmp::min(infected, population.total());
+
+        let mut economy = Self {
+            food: std::cmp::min(food, *storage.get(&Resource::Food).unwrap()),
+            wood: std::cmp::min(wood, *storage.get(&Resource::Wood).unwrap()),
+            stone: std::cmp::min(stone, *storage.get(&Resource::Stone).unwrap()),
+            iron: std::cmp::min(iron, *storage.get(&Resource::Iron).unwrap()),
+            storage,
+            efficiency,
+            population,
+            population_cap,
+            infected,
+            leaderless,
+            frightened,
+            morale,
+            spoiled,
+            burned,
+            constructions,
+            corruption,
+            investigations,
+            weather,
+            forecast,
+            year,
+            years: self.years.clone(),
+            day: self.day + 1,
+            offset: self.offset,
+            specialisation: self.specialisation.clone(),
+            governor,
+            rng,
+        };
+
+        // Random world events
+        for event in EVENTS {
+            if (event.precondition)(&economy, &season)
+                && economy.rng.gen::<f32>() < event.probability
+            {
+                let population = economy.population.total();
+                let amount = (event.effect)(&mut economy);
+                economy.year.deaths += population.saturating_sub(economy.population.total());
+                headlines.push(format!(
+                    "[Day {:>3}] {}",
+                    self.day,
+                    event.headline(&nils.rumour(amount, None))
+                ));
+            }
+        }
+
+        // Turn of the season and the year
+        let next_season = Season::from(self.calendar() + 1);
+        if next_season != season {
+            let (matured, _) = economy.population.age();
+            headlines.push(format!(
+                "[Day {:>3}] {} has arrived. {} children come of age.",
+                self.day,
+                next_season.as_ref(),
+                nils.rumour(matured, None)
+            ));
+        }
+        if Season::year(self.calendar() + 1) != Season::year(self.calendar()) {
+            let summary = economy.year.summary(|count| nils.rumour(count, None));
+            headlines.push(format!("[Day {:>3}] {}", self.day, summary));
+            let next_year = YearStats::new(Season::year(self.calendar() + 1));
+            economy
+                .years
+                .push(std::mem::replace(&mut economy.year, next_year));
         }
 
         if headlines.is_empty() {
             headlines.push(format!("[Day {:>3}] Another lovely day!", self.day))
         }
 
-        (
-            Self {
-                food: std::cmp::min(food, self.storage),
-                wood: std::cmp::min(wood, self.storage),
-                stone: std::cmp::min(stone, self.storage),
-                iron: std::cmp::min(iron, self.storage),
-                storage: storage_size,
-                efficiency,
-                population,
-                population_cap,
-                day: self.day + 1,
-            },
-            headlines,
-        )
+        (economy, headlines)
     }
 }
 
-#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, EnumIter, Serialize, Deserialize)]
 pub enum Resource {
     Food,  //    Excess => +population |  Lack => -population
     Wood,  //    Excess => +pop_cap    |  Lack =>
@@ -217,7 +943,30 @@ pub enum Resource {
     Iron,  //    Excess => +efficiency |  Lack =>
 }
 
-#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, EnumIter)]
+impl Resource {
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            Self::Food => "🍖",
+            Self::Wood => "🪵",
+            Self::Stone => "🪨",
+            Self::Iron => "🪙",
+        }
+    }
+
+    /// Name of the store the resource is kept in
+    pub fn store(&self) -> &'static str {
+        match self {
+            Self::Food => "granary",
+            Self::Wood => "woodshed",
+            Self::Stone => "stoneyard",
+            Self::Iron => "armoury",
+        }
+    }
+}
+
+#[derive(
+    Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, EnumIter, AsRefStr, Serialize, Deserialize,
+)]
 pub enum Building {
     Hunting,
     Lumberyard,
@@ -236,19 +985,19 @@ impl std::fmt::Display for Building {
     }
 }
 
-impl From<usize> for Building {
-    fn from(from: usize) -> Self {
-        match from {
-            0 => Self::Hunting,
-            1 => Self::Lumberyard,
-            2 => Self::Quarry,
-            3 => Self::Mine,
-            _ => unreachable!(),
+impl Building {
+    /// The resource produced by the building
+    pub fn resource(&self) -> Resource {
+        match self {
+            Self::Hunting => Resource::Food,
+            Self::Lumberyard => Resource::Wood,
+            Self::Quarry => Resource::Stone,
+            Self::Mine => Resource::Iron,
         }
     }
 }
 
-#[derive(Debug, EnumIter, AsRefStr)]
+#[derive(Debug, PartialEq, Eq, EnumIter, AsRefStr)]
 pub enum Season {
     Spring,
     Summer,
@@ -257,6 +1006,21 @@ pub enum Season {
 }
 
 impl Season {
+    /// Total number of days in a year
+    pub fn year_length() -> usize {
+        Self::iter().map(|s| s.length()).sum()
+    }
+
+    /// The year a day falls in, starting from year 1
+    pub fn year(day: usize) -> usize {
+        day / Self::year_length() + 1
+    }
+
+    /// The day of the year a day falls in, starting from day 1
+    pub fn day_of_year(day: usize) -> usize {
+        day % Self::year_length() + 1
+    }
+
     pub fn length(&self) -> usize {
         match self {
             Self::Spring => 10,
@@ -266,13 +1030,33 @@ impl Season {
         }
     }
 
+    /// Fraction of the food stockpile that goes bad every day
+    pub fn spoilage(&self) -> f32 {
+        match self {
+            Self::Spring => 0.01,
+            Self::Summer => 0.02,
+            Self::Autumn => 0.01,
+            Self::Winter => 0.0,
+        }
+    }
+
+    /// Wood burned per citizen every day to keep warm
+    pub fn heating(&self) -> f32 {
+        match self {
+            Self::Spring => 0.0,
+            Self::Summer => 0.0,
+            Self::Autumn => 0.0,
+            Self::Winter => 0.05,
+        }
+    }
+
     // [Food, Wood, Stone, Iron]
     pub fn production(&self) -> [f32; 4] {
         match self {
-            Self::Spring => [1.5, 0.2, 0.1, 0.0],
-            Self::Summer => [3.5, 0.0, 0.0, 0.0],
-            Self::Autumn => [1.5, 0.0, 0.1, 0.0],
-            Self::Winter => [0.5, 0.0, 0.0, 0.1],
+            Self::Spring => [2.0, 0.3, 0.1, 0.0],
+            Self::Summer => [4.5, 0.0, 0.0, 0.0],
+            Self::Autumn => [2.0, 0.1, 0.1, 0.0],
+            Self::Winter => [0.7, 0.0, 0.0, 0.1],
         }
     }
 }
@@ -295,9 +1079,7 @@ impl std::fmt::Display for Season {
 
 impl From<usize> for Season {
     fn from(mut day: usize) -> Self {
-        let year: usize = Self::iter().map(|s| s.length()).sum();
-
-        day %= year;
+        day %= Self::year_length();
 
         for season in Self::iter() {
             if day < season.length() {
@@ -0,0 +1,82 @@
+use crate::economy::{Economy, Resource, Season};
+
+/// A random world event. Every day each event whose precondition holds happens with the given
+/// probability, applies its effect, and reports the effect's magnitude, as far as it is known, in
+/// its headline in place of `{}`.
+pub struct Event {
+    pub precondition: fn(&Economy, &Season) -> bool,
+    pub probability: f32,
+    pub effect: fn(&mut Economy) -> usize,
+    pub headline: &'static str,
+}
+
+impl Event {
+    pub fn headline(&self, amount: &str) -> String {
+        self.headline.replace("{}", amount)
+    }
+}
+
+pub const EVENTS: &[Event] = &[
+    Event {
+        precondition: |economy, _| {
+            let population = economy.population.total();
+            population > 20 && population * 10 >= economy.population_cap * 9
+        },
+        probability: 0.01,
+        effect: |economy| {
+            let population = economy.population.total();
+            let sick = std::cmp::min(population / 5, population - economy.infected);
+            economy.infected += sick;
+            sick
+        },
+        headline: "Plague sweeps through the crowded longhouses! {} fall sick.",
+    },
+    Event {
+        precondition: |_, season| matches!(season, Season::Summer | Season::Autumn),
+        probability: 0.02,
+        effect: |economy| {
+            let granary = *economy.storage.get(&Resource::Food).unwrap();
+            let harvest = std::cmp::min(economy.population.workers * 2, granary - economy.food);
+            economy.food += harvest;
+            harvest
+        },
+        headline: "Bumper harvest! {} extra food brought in from the fields.",
+    },
+    Event {
+        precondition: |economy, _| {
+            economy.population.total() + 5 <= economy.population_cap
+                && economy.food >= economy.population.total() * 2
+        },
+        probability: 0.02,
+        effect: |economy| {
+            let migrants = std::cmp::min(economy.population_cap - economy.population.total(), 5);
+            economy.population.workers += migrants;
+            economy.year.immigrants += migrants;
+            migrants
+        },
+        headline: "{} migrants arrive at the gates looking for a new home.",
+    },
+    Event {
+        precondition: |economy, _| economy.wood > 20,
+        probability: 0.01,
+        effect: |economy| {
+            let burned = economy.wood / 2;
+            economy.wood -= burned;
+            burned
+        },
+        headline: "Fire at the lumberyard! {} wood goes up in smoke.",
+    },
+    Event {
+        precondition: |economy, season| {
+            matches!(season, Season::Summer) && economy.food >= economy.population.total() * 3
+        },
+        probability: 0.02,
+        effect: |economy| {
+            let feast = economy.population.total();
+            economy.food -= feast;
+            economy.efficiency += 0.005;
+            feast
+        },
+        headline: "Midsummer festival! {} food shared and spirits lifted.",
+    },
+];
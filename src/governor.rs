@@ -0,0 +1,183 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::{economy::Building, projects::Project};
+
+pub const MAX_SECURITY: usize = 3;
+/// Production in the building the governor drafts labour into
+const DRAFTED_LABOUR: f32 = 1.3;
+/// Production everywhere else while labour is drafted
+const UNDRAFTED_LABOUR: f32 = 0.9;
+/// Share of the workforce on guard duty per level of security
+const SECURITY_LABOUR: f32 = 0.05;
+/// Daily suspicion per level of security
+pub const SECURITY_SUSPICION: f32 = 0.005;
+/// Production everywhere while nobody is in charge
+const VACANT_LABOUR: f32 = 0.8;
+
+/// A decree from the governor
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Order {
+    /// Draft labour into a building, or stop drafting
+    Labour(Option<Building>),
+    /// Put a project before all others, or stop doing so
+    Invest(Option<Project>),
+    Security(usize),
+}
+
+/// How the settlement is run. Left alone, the governor doesn't get in anyone's way.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Governor {
+    pub labour: Option<Building>,
+    /// Started as soon as it can be afforded, whether the stores need it or not
+    pub investment: Option<Project>,
+    /// Guards on the streets, up to `MAX_SECURITY`
+    pub security: usize,
+    /// Days until a replacement arrives after an assassination
+    pub vacancy: usize,
+}
+
+impl Governor {
+    /// Carries out a decree, unless there's nobody in office to issue it. Returns whether it was
+    /// carried out.
+    pub fn apply(&mut self, order: Order) -> bool {
+        if self.vacant() {
+            return false;
+        }
+
+        match order {
+            Order::Labour(labour) => self.labour = labour,
+            Order::Invest(investment) => self.investment = investment,
+            Order::Security(security) => self.security = security.min(MAX_SECURITY),
+        }
+
+        true
+    }
+
+    pub fn vacant(&self) -> bool {
+        self.vacancy > 0
+    }
+
+    /// The governor is killed, and their decrees lapse with them. The seat stays empty for `days`.
+    pub fn assassinate(&mut self, days: usize) {
+        *self = Self {
+            vacancy: days,
+            ..Default::default()
+        };
+    }
+
+    /// The governor a day later. Returns whether a replacement arrived.
+    pub fn next(&self) -> (Self, bool) {
+        let mut governor = self.clone();
+        governor.vacancy = governor.vacancy.saturating_sub(1);
+
+        let arrived = self.vacant() && !governor.vacant();
+        (governor, arrived)
+    }
+
+    /// Production modifier for a building, given where labour is drafted
+    pub fn labour(&self, building: &Building) -> f32 {
+        if self.vacant() {
+            return VACANT_LABOUR;
+        }
+
+        match &self.labour {
+            Some(drafted) if drafted == building => DRAFTED_LABOUR,
+            Some(_) => UNDRAFTED_LABOUR,
+            None => 1.0,
+        }
+    }
+
+    /// Share of the workforce left once the guards are posted
+    pub fn garrison(&self) -> f32 {
+        1.0 - self.security as f32 * SECURITY_LABOUR
+    }
+
+    /// The order drafting labour into the next building, and back to none after the last
+    pub fn next_labour(&self) -> Order {
+        Order::Labour(cycle(Building::iter(), self.labour.as_ref()))
+    }
+
+    /// The order investing in the next project, and back to none after the last
+    pub fn next_investment(&self) -> Order {
+        Order::Invest(cycle(Project::iter(), self.investment.as_ref()))
+    }
+}
+
+/// The item after `current`, going from none to the first and from the last back to none
+fn cycle<T: PartialEq>(mut items: impl Iterator<Item = T>, current: Option<&T>) -> Option<T> {
+    match current {
+        None => items.next(),
+        Some(current) => items.skip_while(|i| i != current).nth(1),
+    }
+}
+
+impl std::fmt::Display for Governor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.vacant() {
+            return write!(f, "Vacant · replacement in {}d", self.vacancy);
+        }
+
+        write!(
+            f,
+            "{} · {} · 🛡️ {}",
+            self.labour
+                .as_ref()
+                .map_or("No draft".to_string(), |b| b.to_string()),
+            self.investment
+                .as_ref()
+                .map_or("No investment", |p| p.as_ref()),
+            self.security
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycle_goes_round_through_none() {
+        let items = || [1, 2, 3].into_iter();
+
+        assert_eq!(cycle(items(), None), Some(1));
+        assert_eq!(cycle(items(), Some(&1)), Some(2));
+        assert_eq!(cycle(items(), Some(&3)), None);
+    }
+
+    #[test]
+    fn next_labour_visits_every_building() {
+        let mut governor = Governor::default();
+
+        for building in Building::iter() {
+            governor.apply(governor.next_labour());
+            assert_eq!(governor.labour, Some(building));
+        }
+
+        governor.apply(governor.next_labour());
+        assert_eq!(governor.labour, None);
+    }
+
+    #[test]
+    fn security_is_capped() {
+        let mut governor = Governor::default();
+
+        assert!(governor.apply(Order::Security(MAX_SECURITY + 5)));
+        assert_eq!(governor.security, MAX_SECURITY);
+    }
+
+    #[test]
+    fn vacant_seat_ignores_orders() {
+        let mut governor = Governor::default();
+        governor.assassinate(2);
+
+        assert!(!governor.apply(Order::Security(1)));
+        assert_eq!(governor.security, 0);
+
+        let (governor, arrived) = governor.next();
+        assert!(!arrived);
+        let (mut governor, arrived) = governor.next();
+        assert!(arrived);
+        assert!(governor.apply(Order::Security(1)));
+    }
+}
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+use crate::economy::Building;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Target {
+    Citizen,
+    Foreman(Building),
+    /// Whoever works in a building, to put the fear into the rest
+    Workers(Building),
+    Guard,
+    Governor,
+}
+
+impl Target {
+    /// The target after this one, cycling back to the start
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Citizen => Self::Foreman(Building::Hunting),
+            Self::Foreman(Building::Hunting) => Self::Foreman(Building::Lumberyard),
+            Self::Foreman(Building::Lumberyard) => Self::Foreman(Building::Quarry),
+            Self::Foreman(Building::Quarry) => Self::Foreman(Building::Mine),
+            Self::Foreman(Building::Mine) => Self::Workers(Building::Hunting),
+            Self::Workers(Building::Hunting) => Self::Workers(Building::Lumberyard),
+            Self::Workers(Building::Lumberyard) => Self::Workers(Building::Quarry),
+            Self::Workers(Building::Quarry) => Self::Workers(Building::Mine),
+            Self::Workers(Building::Mine) => Self::Guard,
+            Self::Guard => Self::Governor,
+            Self::Governor => Self::Citizen,
+        }
+    }
+
+    /// Likelihood of an attempt on the target succeeding
+    pub fn success(&self) -> f32 {
+        match self {
+            Self::Citizen => 0.9,
+            Self::Foreman(_) => 0.6,
+            Self::Workers(_) => 0.8,
+            Self::Guard => 0.5,
+            Self::Governor => 0.2,
+        }
+    }
+
+    /// Likelihood of a hitman not getting away after a failed attempt
+    pub fn risk(&self) -> f32 {
+        match self {
+            Self::Citizen => 0.2,
+            Self::Foreman(_) => 0.4,
+            Self::Workers(_) => 0.3,
+            Self::Guard => 0.6,
+            Self::Governor => 0.8,
+        }
+    }
+
+    pub fn short(&self) -> String {
+        match self {
+            Self::Citizen => "Anyone".to_string(),
+            Self::Foreman(building) => format!("Foreman {}", building.resource().emoji()),
+            Self::Workers(building) => format!("Workers {}", building.resource().emoji()),
+            Self::Guard => "Guards".to_string(),
+            Self::Governor => "Governor".to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for Target {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Citizen => write!(f, "a citizen"),
+            Self::Foreman(building) => write!(f, "the foreman of the {}", building),
+            Self::Workers(building) => write!(f, "a worker of the {}", building),
+            Self::Guard => write!(f, "a guard"),
+            Self::Governor => write!(f, "the governor"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Success,
+    Escaped,
+    Killed,
+    Captured,
+}
+
+#[derive(Clone, Debug)]
+pub struct Hit {
+    pub name: String,
+    pub target: Target,
+    pub outcome: Outcome,
+    /// Comrades arrested after the hitman was interrogated
+    pub exposed: usize,
+}
@@ -0,0 +1,181 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum::IntoEnumIterator;
+
+use crate::economy::{Economy, Resource};
+
+/// Intel quality at which reports are exact
+const CLEAR_INTEL: f32 = 0.8;
+/// Intel quality below which no reports come in at all
+const POOR_INTEL: f32 = 0.3;
+
+/// What the nihilists last heard about some figure
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Report {
+    pub value: usize,
+    /// How far off the value might be
+    pub error: usize,
+    pub day: usize,
+}
+
+impl Report {
+    /// The report as shown to the player, given the current day
+    pub fn estimate(&self, today: usize) -> String {
+        let estimate = if self.error == 0 {
+            self.value.to_string()
+        } else {
+            format!(
+                "{}-{}",
+                self.value.saturating_sub(self.error),
+                self.value + self.error
+            )
+        };
+
+        match today.saturating_sub(self.day) {
+            0 => estimate,
+            days => format!("{} ({}d ago)", estimate, days),
+        }
+    }
+}
+
+/// What the nihilists know of a year in the chronicle
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Chronicle {
+    pub year: usize,
+    pub births: Option<Report>,
+    pub deaths: Option<Report>,
+    pub immigrants: Option<Report>,
+    pub emigrants: Option<Report>,
+    pub production: HashMap<Resource, Report>,
+}
+
+/// The nihilists' picture of the settlement, only as good as their sources
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Intel {
+    pub stockpiles: HashMap<Resource, Report>,
+    pub capacities: HashMap<Resource, Report>,
+    pub population: Option<Report>,
+    pub housing: Option<Report>,
+    /// In percent
+    pub efficiency: Option<Report>,
+    /// In percent
+    pub morale: Option<Report>,
+    /// Food gone bad today
+    pub spoiled: Option<Report>,
+    /// Wood burned today to keep warm
+    pub burned: Option<Report>,
+    pub year: Chronicle,
+    pub last_year: Chronicle,
+}
+
+impl Intel {
+    /// Gathers reports on the economy. `general` is the quality of intel on the settlement as a
+    /// whole, `local` that of intel on a resource, between 0 and 1.
+    pub fn observe<R: Rng>(
+        &mut self,
+        economy: &Economy,
+        general: f32,
+        local: impl Fn(&Resource) -> f32,
+        rng: &mut R,
+    ) {
+        let day = economy.day;
+
+        // A new year starts a new page of the chronicle
+        if economy.year.year != self.year.year {
+            self.last_year = std::mem::take(&mut self.year);
+            self.year.year = economy.year.year;
+        }
+
+        for (i, resource) in Resource::iter().enumerate() {
+            let quality = local(&resource);
+
+            if let Some(report) = Self::report(economy.stock(&resource), quality, day, rng) {
+                self.stockpiles.insert(resource.clone(), report);
+            }
+            if let Some(report) = Self::report(economy.capacity(&resource), quality, day, rng) {
+                self.capacities.insert(resource.clone(), report);
+            }
+            if let Some(report) = Self::report(economy.year.production[i], quality, day, rng) {
+                self.year.production.insert(resource, report);
+            }
+        }
+
+        let population = economy.population.total();
+        let efficiency = (economy.efficiency * 100.0) as usize;
+        let morale = (economy.morale * 100.0) as usize;
+
+        let update = |report: &mut Option<Report>, value, quality, rng: &mut R| {
+            if let Some(new) = Self::report(value, quality, day, rng) {
+                *report = Some(new);
+            }
+        };
+
+        update(&mut self.population, population, general, rng);
+        update(&mut self.housing, economy.population_cap, general, rng);
+        update(&mut self.efficiency, efficiency, general, rng);
+        update(&mut self.morale, morale, general, rng);
+        update(
+            &mut self.spoiled,
+            economy.spoiled,
+            local(&Resource::Food),
+            rng,
+        );
+        update(
+            &mut self.burned,
+            economy.burned,
+            local(&Resource::Wood),
+            rng,
+        );
+
+        let year = &economy.year;
+        update(&mut self.year.births, year.births, general, rng);
+        update(&mut self.year.deaths, year.deaths, general, rng);
+        update(&mut self.year.immigrants, year.immigrants, general, rng);
+        update(&mut self.year.emigrants, year.emigrants, general, rng);
+    }
+
+    /// A count as it gets around town: exact with clear intel, a range with some, and a shrug
+    /// with none
+    pub fn rumour<R: Rng>(count: usize, quality: f32, rng: &mut R) -> String {
+        Self::estimate(Self::report(count, quality, 0, rng).as_ref(), 0)
+    }
+
+    /// A report on a figure given the quality of intel on it, if any comes in at all
+    fn report<R: Rng>(value: usize, quality: f32, day: usize, rng: &mut R) -> Option<Report> {
+        if quality < POOR_INTEL {
+            return None;
+        }
+
+        if quality >= CLEAR_INTEL {
+            return Some(Report {
+                value,
+                error: 0,
+                day,
+            });
+        }
+
+        let error = ((value as f32 * (1.0 - quality) * 0.5).ceil() as usize).max(1);
+        let noise = rng.gen_range(0..=2 * error);
+
+        Some(Report {
+            value: (value + noise).saturating_sub(error),
+            error,
+            day,
+        })
+    }
+
+    /// The report on a figure shown to the player, or a shrug if nothing has come in yet
+    pub fn estimate(report: Option<&Report>, today: usize) -> String {
+        report.map_or("?".to_string(), |r| r.estimate(today))
+    }
+
+    /// Like `estimate`, but short enough for a table: only the best guess, marked if uncertain
+    pub fn approximate(report: Option<&Report>) -> String {
+        match report {
+            Some(report) if report.error == 0 => report.value.to_string(),
+            Some(report) => format!("~{}", report.value),
+            None => "?".to_string(),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Helpers for tests that play against the servers over a loopback socket. The servers only move
+//! when served, so the tests keep serving while they wait on an answer.
+
+use std::{
+    io::{BufRead, BufReader},
+    net::{SocketAddr, TcpStream},
+    time::{Duration, Instant},
+};
+
+/// How long to wait on a server before giving up on it
+const TIMEOUT: Duration = Duration::from_secs(5);
+/// How long to wait between goes at serving
+const POLL: Duration = Duration::from_millis(10);
+
+/// Keeps at `step` until it says it's done, or until it's clear it never will be
+pub fn until(mut step: impl FnMut() -> bool) {
+    let start = Instant::now();
+
+    while !step() && start.elapsed() < TIMEOUT {
+        std::thread::sleep(POLL);
+    }
+}
+
+/// Connects to a server, with a reader that soon gives up so the caller can get on with serving
+pub fn connect(addr: SocketAddr) -> (TcpStream, BufReader<TcpStream>) {
+    let stream = TcpStream::connect(addr).unwrap();
+    stream.set_read_timeout(Some(POLL)).unwrap();
+    let reader = BufReader::new(stream.try_clone().unwrap());
+
+    (stream, reader)
+}
+
+/// Reads a whole line off a server, doing `work` while waiting for it
+pub fn line(reader: &mut impl BufRead, mut work: impl FnMut()) -> String {
+    let start = Instant::now();
+    let mut line = String::new();
+
+    while !line.ends_with('\n') && start.elapsed() < TIMEOUT {
+        work();
+        let _ = reader.read_line(&mut line);
+    }
+
+    line.trim().to_string()
+}
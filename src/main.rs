@@ -1,7 +1,30 @@
+pub mod api;
+pub mod bribery;
+pub mod chronicle;
+pub mod coop;
+pub mod demographics;
+pub mod doctrines;
 pub mod economy;
+pub mod events;
+pub mod governor;
+pub mod hitmen;
+pub mod intel;
+#[cfg(test)]
+pub mod loopback;
+pub mod multiplayer;
+pub mod network;
 pub mod nihilists;
+pub mod operatives;
+pub mod projects;
+pub mod propaganda;
+pub mod rivals;
+pub mod screen;
 pub mod state;
+pub mod trade;
+pub mod trials;
 pub mod ui;
+pub mod weather;
+pub mod world;
 
 use crossterm::{
     event::{self, Event, KeyCode},
@@ -9,19 +32,51 @@ use crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 
+use api::Api;
+use multiplayer::Host;
 use state::State;
 use std::time::{Duration, Instant};
 use tui::{backend::CrosstermBackend, Terminal};
 
+/// The value passed after a command line flag, if any
+fn arg(flag: &str) -> Option<String> {
+    std::env::args().skip_while(|arg| arg != flag).nth(1)
+}
+
 fn main() {
+    if let Some(addr) = arg("--join") {
+        multiplayer::join(&addr).expect("Should be able to reach the host");
+        return;
+    }
+
+    if let Some(addr) = arg("--coop") {
+        coop::play(&addr).expect("Should be able to reach the server");
+        return;
+    }
+
+    let mut state = match arg("--seed") {
+        Some(seed) => State::seeded(seed.parse().expect("Seed should be a number")),
+        None => State::default(),
+    };
+
+    if let Some(addr) = arg("--serve") {
+        coop::serve(&addr, state).expect("Should be able to serve");
+        return;
+    }
+
+    // The governor plays from the same keyboard, or from across the network
+    let hotseat = std::env::args().any(|arg| arg == "--hotseat");
+    let mut host = arg("--host").map(|addr| Host::listen(&addr).expect("Should be able to listen"));
+
+    // Bots and tools get in through the API
+    let mut api = arg("--api").map(|addr| Api::listen(&addr).expect("Should be able to listen"));
+
     enable_raw_mode().unwrap();
     let mut stdout = std::io::stdout();
     execute!(stdout, EnterAlternateScreen).unwrap();
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend).unwrap();
 
-    let mut state = State::default();
-
     let day_duration = Duration::from_millis(500);
     let tick_duration = Duration::from_millis(100);
     let mut last_tick = Instant::now();
@@ -35,6 +90,15 @@ fn main() {
                     KeyCode::Char('Q') | KeyCode::Char('q') => should_quit = true,
                     KeyCode::Char('+') => state.plus(),
                     KeyCode::Char('-') => state.minus(),
+                    KeyCode::Char('T') | KeyCode::Char('t') => state.retarget(),
+                    KeyCode::Char('C') | KeyCode::Char('c') => state.campaign(),
+                    KeyCode::Char('$') => state.pay(),
+                    KeyCode::Char('M') | KeyCode::Char('m') => state.travel(),
+                    KeyCode::Char('L') | KeyCode::Char('l') if hotseat => state.labour(),
+                    KeyCode::Char('I') | KeyCode::Char('i') if hotseat => state.invest(),
+                    KeyCode::Char('>') if hotseat => state.secure(),
+                    KeyCode::Char('<') if hotseat => state.relax(),
+                    KeyCode::Tab => state.switch_screen(),
                     KeyCode::Left => state.left(),
                     KeyCode::Up => state.up(),
                     KeyCode::Right => state.right(),
@@ -46,21 +110,34 @@ fn main() {
 
         let now = Instant::now();
 
-        if now.duration_since(last_tick) >= day_duration {
+        if let Some(host) = host.as_mut() {
+            host.serve(&mut state);
+        }
+
+        if let Some(api) = api.as_mut() {
+            api.serve(&mut state);
+        }
+        let paused = api.as_ref().is_some_and(|api| api.paused);
+
+        if !paused && now.duration_since(last_tick) >= day_duration {
             state = state.next();
             last_tick = Instant::now();
+
+            if let Some(host) = host.as_mut() {
+                host.report(&state);
+            }
         }
 
         terminal.draw(|frame| ui::draw(frame, &state)).unwrap();
 
-        if state.economy.extinct() || should_quit {
+        if state.extinct() || should_quit {
             disable_raw_mode().unwrap();
             execute!(terminal.backend_mut(), LeaveAlternateScreen).unwrap();
             terminal.clear().unwrap();
             terminal.show_cursor().unwrap();
             disable_raw_mode().unwrap();
 
-            if state.economy.extinct() {
+            if state.extinct() {
                 println!("The human race is extinct! 🎉");
             }
 
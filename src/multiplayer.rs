@@ -0,0 +1,293 @@
+//! Two-player games over a local TCP socket. The host plays the nihilists in the terminal, while
+//! the governor connects and sends one command per line:
+//!
+//! ```text
+//! TOWN Tver          govern another settlement
+//! LABOUR Quarry      draft labour into a building, or NONE
+//! INVEST Granary     put a project first, or NONE
+//! SECURITY 2         post guards, from 0 to 3
+//! ```
+//!
+//! Every command is answered with `OK` or `ERR <reason>`. Only one governor may be connected at a
+//! time; anyone else is turned away with an `ERR`. At the end of every day the host sends
+//! a `DAY` line with the governed settlement's figures, followed by a `NEWS` line per headline.
+
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    str::FromStr,
+    sync::{
+        mpsc::{self, Receiver},
+        Arc, Mutex,
+    },
+};
+
+use strum::IntoEnumIterator;
+
+use crate::{
+    economy::Building,
+    governor::{Order, MAX_SECURITY},
+    projects::Project,
+    state::State,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Command {
+    Town(String),
+    Order(Order),
+}
+
+impl FromStr for Command {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut words = line.split_whitespace();
+        let command = words.next().unwrap_or_default().to_uppercase();
+        let argument = words.next().unwrap_or_default();
+        let none = argument.eq_ignore_ascii_case("none");
+
+        match command.as_str() {
+            "TOWN" => Ok(Self::Town(argument.to_string())),
+            "LABOUR" if none => Ok(Self::Order(Order::Labour(None))),
+            "LABOUR" => Building::iter()
+                .find(|b| b.as_ref().eq_ignore_ascii_case(argument))
+                .map(|b| Self::Order(Order::Labour(Some(b))))
+                .ok_or(format!("No such building: {}", argument)),
+            "INVEST" if none => Ok(Self::Order(Order::Invest(None))),
+            "INVEST" => Project::iter()
+                .find(|p| p.as_ref().eq_ignore_ascii_case(argument))
+                .map(|p| Self::Order(Order::Invest(Some(p))))
+                .ok_or(format!("No such project: {}", argument)),
+            "SECURITY" => argument
+                .parse()
+                .ok()
+                .filter(|s| *s <= MAX_SECURITY)
+                .map(|s| Self::Order(Order::Security(s)))
+                .ok_or(format!(
+                    "Security goes from 0 to {}: {}",
+                    MAX_SECURITY, argument
+                )),
+            _ => Err(format!("Unknown command: {}", line.trim())),
+        }
+    }
+}
+
+/// The nihilists' end of the game, taking in the governor's commands
+pub struct Host {
+    commands: Receiver<String>,
+    governor: Arc<Mutex<Option<TcpStream>>>,
+    addr: SocketAddr,
+    /// The settlement being governed
+    town: usize,
+    /// Headlines of the governed settlement already sent
+    reported: usize,
+}
+
+impl Host {
+    /// Waits for a governor on the given address in the background. Governors who drop out can
+    /// connect again, but nobody else gets in while one is connected.
+    pub fn listen(addr: &str) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let (sender, commands) = mpsc::channel();
+        let governor = Arc::new(Mutex::new(None));
+
+        let connected = governor.clone();
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().map_while(Result::ok) {
+                let mut governor = connected.lock().unwrap();
+                if governor.is_some() {
+                    let _ = writeln!(stream, "ERR Another governor is already connected");
+                    continue;
+                }
+
+                let (Ok(writer), Ok(peer)) = (stream.try_clone(), stream.peer_addr()) else {
+                    continue;
+                };
+                *governor = Some(writer);
+                drop(governor);
+
+                let sender = sender.clone();
+                let connected = connected.clone();
+                std::thread::spawn(move || {
+                    for line in BufReader::new(stream).lines().map_while(Result::ok) {
+                        if sender.send(line).is_err() {
+                            break;
+                        }
+                    }
+
+                    // Make way for the next governor, unless the host has already done so
+                    let mut governor = connected.lock().unwrap();
+                    if governor.as_ref().and_then(|g| g.peer_addr().ok()) == Some(peer) {
+                        *governor = None;
+                    }
+                });
+            }
+        });
+
+        Ok(Self {
+            commands,
+            governor,
+            addr,
+            town: 0,
+            reported: 0,
+        })
+    }
+
+    /// The address governors connect to
+    pub fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Carries out whatever the governor has sent since last time
+    pub fn serve(&mut self, state: &mut State) {
+        let lines: Vec<String> = self.commands.try_iter().collect();
+
+        for line in lines {
+            let reply = match line.parse() {
+                Ok(Command::Town(name)) => {
+                    match state
+                        .world
+                        .settlements
+                        .iter()
+                        .position(|s| s.name.eq_ignore_ascii_case(&name))
+                    {
+                        Some(town) => {
+                            self.town = town;
+                            self.reported = state.world.settlements[town].headlines.len();
+                            "OK".to_string()
+                        }
+                        None => format!("ERR No such town: {}", name),
+                    }
+                }
+                Ok(Command::Order(order)) => {
+                    if state.govern(self.town, order) {
+                        "OK".to_string()
+                    } else {
+                        "ERR The governor's seat is vacant".to_string()
+                    }
+                }
+                Err(error) => format!("ERR {}", error),
+            };
+
+            self.send(&reply);
+        }
+    }
+
+    /// Tells the governor how the day went
+    pub fn report(&mut self, state: &State) {
+        let settlement = &state.world.settlements[self.town];
+        let economy = &settlement.economy;
+
+        self.send(&format!(
+            "DAY {} {} POP {} FOOD {} WOOD {} STONE {} IRON {} MORALE {:.0}% GOVERNOR {}",
+            economy.day,
+            settlement.name,
+            economy.population.total(),
+            economy.food,
+            economy.wood,
+            economy.stone,
+            economy.iron,
+            economy.morale * 100.0,
+            economy.governor
+        ));
+
+        // Headlines come in newest first
+        let fresh = settlement.headlines.len().saturating_sub(self.reported);
+        for headline in settlement.headlines[..fresh].iter().rev() {
+            self.send(&format!("NEWS {}", headline));
+        }
+        self.reported = settlement.headlines.len();
+
+        if state.extinct() {
+            self.send("EXTINCT");
+        }
+    }
+
+    /// Sends the governor a line, forgetting about them if they've hung up
+    fn send(&self, line: &str) {
+        let mut governor = self.governor.lock().unwrap();
+
+        if let Some(stream) = governor.as_mut() {
+            if writeln!(stream, "{}", line).is_err() {
+                *governor = None;
+            }
+        }
+    }
+}
+
+/// The governor's end of the game. Lines typed in are sent to the host, and whatever the host
+/// sends back is printed, until either side hangs up.
+pub fn join(addr: &str) -> std::io::Result<()> {
+    let stream = TcpStream::connect(addr)?;
+    let reader = BufReader::new(stream.try_clone()?);
+
+    std::thread::spawn(move || {
+        for line in reader.lines().map_while(Result::ok) {
+            println!("{}", line);
+        }
+
+        println!("The host has hung up.");
+        std::process::exit(0);
+    });
+
+    let mut writer = stream;
+    for line in std::io::stdin().lock().lines().map_while(Result::ok) {
+        writeln!(writer, "{}", line)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loopback;
+
+    #[test]
+    fn parses_commands() {
+        assert_eq!(
+            "labour quarry".parse(),
+            Ok(Command::Order(Order::Labour(Some(Building::Quarry))))
+        );
+        assert_eq!(
+            "INVEST none".parse(),
+            Ok(Command::Order(Order::Invest(None)))
+        );
+        assert_eq!("SECURITY 2".parse(), Ok(Command::Order(Order::Security(2))));
+        assert_eq!("TOWN Tver".parse(), Ok(Command::Town("Tver".to_string())));
+    }
+
+    #[test]
+    fn rejects_bad_commands() {
+        assert!("SECURITY 9".parse::<Command>().is_err());
+        assert!("SECURITY high".parse::<Command>().is_err());
+        assert!("LABOUR Palace".parse::<Command>().is_err());
+        assert!("INVEST".parse::<Command>().is_err());
+        assert!("DANCE".parse::<Command>().is_err());
+    }
+
+    #[test]
+    fn serves_over_loopback() {
+        let mut state = State::seeded(1);
+        let mut host = Host::listen("127.0.0.1:0").unwrap();
+
+        let (mut governor, mut reader) = loopback::connect(host.local_addr());
+
+        writeln!(governor, "LABOUR Quarry").unwrap();
+        assert_eq!(loopback::line(&mut reader, || host.serve(&mut state)), "OK");
+        assert_eq!(
+            state.world.settlements[0].economy.governor.labour,
+            Some(Building::Quarry)
+        );
+
+        writeln!(governor, "SECURITY 9").unwrap();
+        assert!(loopback::line(&mut reader, || host.serve(&mut state)).starts_with("ERR"));
+
+        // Nobody else gets in while the governor is connected
+        let intruder = TcpStream::connect(host.local_addr()).unwrap();
+        let mut line = String::new();
+        BufReader::new(intruder).read_line(&mut line).unwrap();
+        assert!(line.starts_with("ERR"));
+    }
+}
@@ -0,0 +1,159 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::operatives::Operative;
+
+const HANDLER_SPAN: usize = 3;
+const MIN_CELL_SIZE: usize = 2;
+const MAX_CELL_SIZE: usize = 8;
+const CELL_EFFICIENCY: f32 = 0.05;
+const CONFESSION_LIKELIHOOD: f32 = 0.6;
+const HANDLER_CONFESSION_LIKELIHOOD: f32 = 0.3;
+
+/// How the nihilists are organised. Every operative belongs to a cell of up to `cell_size`, and
+/// every `HANDLER_SPAN` cells report to the same handler. Bigger cells coordinate better but an
+/// arrest exposes more comrades.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Network {
+    pub cell_size: usize,
+}
+
+impl Default for Network {
+    fn default() -> Self {
+        Self { cell_size: 4 }
+    }
+}
+
+impl Network {
+    /// Efficiency modifier from the cell size, neutral at the default size
+    pub fn modifier(&self) -> f32 {
+        1.0 + (self.cell_size as f32 - 4.0) * CELL_EFFICIENCY
+    }
+
+    pub fn larger(&mut self) {
+        if self.cell_size < MAX_CELL_SIZE {
+            self.cell_size += 1;
+        }
+    }
+
+    pub fn smaller(&mut self) {
+        if self.cell_size > MIN_CELL_SIZE {
+            self.cell_size -= 1;
+        }
+    }
+
+    /// The cell a new member joins: the first one with room to spare
+    pub fn place(&self, operatives: &[Operative]) -> usize {
+        (0..)
+            .find(|&cell| operatives.iter().filter(|o| o.cell == cell).count() < self.cell_size)
+            .unwrap()
+    }
+
+    /// The handler a cell reports to
+    pub fn handler(cell: usize) -> usize {
+        cell / HANDLER_SPAN
+    }
+
+    /// Member counts of every handler's cells
+    pub fn handlers(operatives: &[Operative]) -> Vec<Vec<usize>> {
+        let mut handlers: BTreeMap<usize, BTreeMap<usize, usize>> = BTreeMap::new();
+        for operative in operatives {
+            *handlers
+                .entry(Self::handler(operative.cell))
+                .or_default()
+                .entry(operative.cell)
+                .or_default() += 1;
+        }
+
+        handlers
+            .into_values()
+            .map(|cells| cells.into_values().collect())
+            .collect()
+    }
+
+    /// Interrogates an operative taken away from the comrades still at large. Returns the
+    /// positions of those they expose: their cell if they talk, and the rest of their handler's
+    /// cells if the handler is given up too. The more loyal the operative, the less likely they
+    /// are to talk.
+    pub fn interrogate<R: Rng>(
+        &self,
+        arrested: &Operative,
+        operatives: &[Operative],
+        rng: &mut R,
+    ) -> Vec<usize> {
+        let confession_likelihood = CONFESSION_LIKELIHOOD * 2.0 * (1.0 - arrested.loyalty);
+        if rng.gen::<f32>() >= confession_likelihood {
+            return vec![];
+        }
+
+        let handler = rng.gen::<f32>() < HANDLER_CONFESSION_LIKELIHOOD;
+        Self::linked(arrested.cell, handler, operatives)
+    }
+
+    /// Positions of the operatives in a cell, and in the rest of its handler's cells if asked for
+    pub fn linked(cell: usize, handler: bool, operatives: &[Operative]) -> Vec<usize> {
+        (0..operatives.len())
+            .filter(|&i| {
+                operatives[i].cell == cell
+                    || (handler && Self::handler(operatives[i].cell) == Self::handler(cell))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn operatives(cells: &[usize]) -> Vec<Operative> {
+        let mut rng = rand::thread_rng();
+        cells
+            .iter()
+            .map(|&cell| Operative {
+                cell,
+                ..Operative::random(&mut rng)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn place_fills_the_first_cell_with_room() {
+        let network = Network { cell_size: 2 };
+
+        assert_eq!(network.place(&operatives(&[])), 0);
+        assert_eq!(network.place(&operatives(&[0])), 0);
+        assert_eq!(network.place(&operatives(&[0, 0, 1])), 1);
+        assert_eq!(network.place(&operatives(&[0, 0, 2, 2])), 1);
+    }
+
+    #[test]
+    fn handlers_group_cells() {
+        let handlers = Network::handlers(&operatives(&[0, 0, 1, 3, 3, 3]));
+
+        assert_eq!(handlers, vec![vec![2, 1], vec![3]]);
+    }
+
+    #[test]
+    fn interrogate_exposes_only_linked_comrades() {
+        let network = Network::default();
+        let at_large = operatives(&[0, 0, 1, 2, 3, 4]);
+        let mut arrested = operatives(&[0]).remove(0);
+        let mut rng = rand::thread_rng();
+
+        // The disloyal always talk, and sometimes give up their handler's other cells
+        arrested.loyalty = 0.0;
+        for _ in 0..100 {
+            let exposed = network.interrogate(&arrested, &at_large, &mut rng);
+            assert!(exposed == vec![0, 1] || exposed == vec![0, 1, 2, 3]);
+        }
+
+        // The fiercely loyal never do
+        arrested.loyalty = 1.0;
+        for _ in 0..100 {
+            assert!(network
+                .interrogate(&arrested, &at_large, &mut rng)
+                .is_empty());
+        }
+    }
+}
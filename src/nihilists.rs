@@ -1,69 +1,119 @@
-use rand::{prelude::ThreadRng, Rng};
+use rand::{prelude::ThreadRng, seq::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, fmt::Display};
 use strum::IntoEnumIterator;
 
-use crate::economy::Building;
+use crate::{
+    bribery::Bribe,
+    doctrines::Doctrine,
+    economy::{Building, Economy},
+    hitmen::{Hit, Outcome, Target},
+    intel::Intel,
+    network::Network,
+    operatives::{Operative, Role},
+    propaganda::Campaign,
+    trials::{Charge, Trial},
+};
 
 const AGITATOR_MODIFIER: f32 = 0.90;
+const AGITATOR_DISCONTENT: f32 = 0.02;
 const SABOTEUR_LIKELIHOOD: f32 = 0.01;
 const EMBEZZLER_MODIFIER: f32 = 0.99;
 const RECRUITER_LIKELIHOOD: f32 = 0.01;
 const HITMAN_LIKELIHOOD: f32 = 0.01;
+const PLAGUEBEARER_LIKELIHOOD: f32 = 0.05;
+const PLAGUEBEARER_INFECTIONS: usize = 2;
+const PLAGUEBEARER_RISK: f32 = 0.02;
+const PLAGUEBEARER_CONTAGION: f32 = 0.3;
+const RAIDER_LIKELIHOOD: f32 = 0.05;
+const RAIDER_SUSPICION: f32 = 0.02;
 const EFFICIENCY_STEP: f32 = 0.001;
+const GUARD_DAYS: usize = 7;
+const UNGUARDED_RISK: f32 = 0.5;
+const CAPTURE_LIKELIHOOD: f32 = 0.5;
+const MAX_EFFICIENCY: f32 = 2.0;
+const DOCTRINE_BOOST: f32 = 1.25;
+const ORATORY_LIKELIHOOD: f32 = 0.005;
+const EMBEZZLED_RUBLES: usize = 1;
+const UNDERCOVER_INTEL: f32 = 0.1;
+const EMBEDDED_INTEL: f32 = 0.25;
+const PROPAGANDA_MORALE: f32 = 0.02;
+const PROPAGANDA_RECRUITMENT: f32 = 0.005;
+const PROPAGANDA_SUSPICION: f32 = 0.003;
+const PRINTING_COST: usize = 2;
+const SUSPICION_DECAY: f32 = 0.01;
+const INFORMANT_LIKELIHOOD: f32 = 0.15;
+const INFORMANT_REPORT_LIKELIHOOD: f32 = 0.02;
+const INFORMANT_SUSPICION: f32 = 0.05;
+const COUNTERSPY_LIKELIHOOD: f32 = 0.05;
+const MARTYR_DECAY: f32 = 0.98;
+const MARTYR_RECRUITMENT: f32 = 0.02;
+const MARTYR_DISCONTENT: f32 = 0.01;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Nihilists {
-    pub agitators: HashMap<Building, usize>,
-    pub saboteurs: HashMap<Building, usize>,
-    pub embezzlers: HashMap<Building, usize>,
+    pub operatives: Vec<Operative>,
 
-    pub undercover: usize,
-    pub recruiters: usize,
-    pub hitmen: usize,
+    pub target: Target,
+    pub unguarded: usize,
+
+    pub network: Network,
 
     pub efficiency: f32,
 
+    pub doctrines: Vec<Doctrine>,
+    pub treasury: usize,
+
+    pub intel: Intel,
+
+    pub campaign: Campaign,
+    /// How closely the government is watching, between 0 and 1
+    pub suspicion: f32,
+
+    /// Bribes paid for but not yet delivered
+    pub bribes: Vec<Bribe>,
+
+    /// Comrades in the dock
+    pub trials: Vec<Trial>,
+    pub martyrs: usize,
+    /// How much the executions still stir people up
+    pub martyrdom: f32,
+
+    /// Share of would-be recruits a rival faction gets to first, between 0 and 1
+    pub competition: f32,
+    /// Outrages committed today, for the papers to pin on someone
+    pub deeds: usize,
+
+    /// Id for the next operative to join
+    next_id: usize,
+
+    #[serde(skip)]
     rng: ThreadRng,
 }
 
 impl Display for Nihilists {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "-- Nihilists ------------")?;
-        writeln!(f, "   Agitators Saboteurs Embezzlers")?;
-        writeln!(
-            f,
-            "🍖 {:<9} {:<9} {}",
-            self.agitators.get(&Building::Hunting).unwrap(),
-            self.saboteurs.get(&Building::Hunting).unwrap(),
-            self.embezzlers.get(&Building::Hunting).unwrap(),
-        )?;
-        writeln!(
-            f,
-            "🪵 {:<9} {:<9} {}",
-            self.agitators.get(&Building::Lumberyard).unwrap(),
-            self.saboteurs.get(&Building::Lumberyard).unwrap(),
-            self.embezzlers.get(&Building::Lumberyard).unwrap(),
-        )?;
-        writeln!(
-            f,
-            "🪨 {:<9} {:<9} {}",
-            self.agitators.get(&Building::Quarry).unwrap(),
-            self.saboteurs.get(&Building::Quarry).unwrap(),
-            self.embezzlers.get(&Building::Quarry).unwrap(),
-        )?;
-        writeln!(
-            f,
-            "🪙 {:<9} {:<9} {}",
-            self.agitators.get(&Building::Mine).unwrap(),
-            self.saboteurs.get(&Building::Mine).unwrap(),
-            self.embezzlers.get(&Building::Mine).unwrap(),
-        )?;
+        writeln!(f, "   Agitators Saboteurs Embezzlers Plaguebearers")?;
+        for building in Building::iter() {
+            writeln!(
+                f,
+                "{} {:<9} {:<9} {:<10} {}",
+                building.resource().emoji(),
+                self.count(&Role::Agitator(building.clone())),
+                self.count(&Role::Saboteur(building.clone())),
+                self.count(&Role::Embezzler(building.clone())),
+                self.count(&Role::Plaguebearer(building.clone())),
+            )?;
+        }
         writeln!(f)?;
-        writeln!(f, "🕵️ {:>5}", self.undercover)?;
-        writeln!(f, "🤝 {:>5}", self.recruiters)?;
-        writeln!(f, "🔪 {:>5}", self.hitmen)?;
+        writeln!(f, "🕵️ {:>5}", self.count(&Role::Undercover))?;
+        writeln!(f, "🤝 {:>5}", self.count(&Role::Recruiter))?;
+        writeln!(f, "🔪 {:>5}", self.count(&Role::Hitman))?;
+        writeln!(f, "📰 {:>5}", self.count(&Role::Propagandist))?;
         writeln!(f)?;
         writeln!(f, "🏭 {:>4.1}%", (self.efficiency - 1.0) * 100.0)?;
+        writeln!(f, "💰 {:>5}", self.treasury)?;
         writeln!(f, "-------------------------")
     }
 }
@@ -72,54 +122,684 @@ impl Nihilists {
     pub fn next(&self) -> Self {
         let mut cloned = self.clone();
 
-        if RECRUITER_LIKELIHOOD * cloned.recruiters as f32 * cloned.efficiency
+        if RECRUITER_LIKELIHOOD * cloned.skill(&Role::Recruiter) * cloned.effectiveness()
             > cloned.rng.gen::<f32>()
         {
-            cloned.undercover += 1;
+            cloned.recruit();
+            cloned.credit(&Role::Recruiter);
+        }
+
+        if cloned.learned(&Doctrine::Oratory) {
+            let agitation: f32 = Building::iter()
+                .map(|b| cloned.skill(&Role::Agitator(b)))
+                .sum();
+
+            if ORATORY_LIKELIHOOD * agitation * cloned.effectiveness() > cloned.rng.gen::<f32>() {
+                cloned.recruit();
+            }
+        }
+
+        // Propaganda, printed if the treasury can pay for it
+        let propagandists = cloned.count(&Role::Propagandist) - cloned.pending(&Role::Propagandist);
+        if propagandists > 0 {
+            let cost = propagandists * PRINTING_COST;
+
+            cloned.campaign.days += 1;
+            cloned.campaign.printed = cloned.treasury >= cost;
+            if cloned.campaign.printed {
+                cloned.treasury -= cost;
+            }
+        } else {
+            cloned.campaign.days = 0;
+            cloned.campaign.printed = false;
+        }
+
+        let reach = cloned.reach();
+        let theme = cloned.campaign.theme.clone();
+        if reach * theme.recruitment() * PROPAGANDA_RECRUITMENT > cloned.rng.gen::<f32>() {
+            cloned.recruit();
+            cloned.credit(&Role::Propagandist);
+        }
+
+        cloned.suspicion = (cloned.suspicion + reach * theme.suspicion() * PROPAGANDA_SUSPICION
+            - SUSPICION_DECAY)
+            .clamp(0.0, 1.0);
+
+        // The executed are not forgotten quickly
+        if MARTYR_RECRUITMENT * cloned.martyrdom > cloned.rng.gen::<f32>() {
+            cloned.recruit();
+        }
+        cloned.martyrdom *= MARTYR_DECAY;
+
+        let undercover = cloned
+            .operatives
+            .iter()
+            .filter(|o| o.role == Role::Undercover && o.ready())
+            .count();
+        cloned.efficiency =
+            (cloned.efficiency + undercover as f32 * EFFICIENCY_STEP).min(MAX_EFFICIENCY);
+
+        for operative in cloned.operatives.iter_mut() {
+            operative.busy = operative.busy.saturating_sub(1);
         }
 
-        cloned.efficiency += cloned.undercover as f32 * EFFICIENCY_STEP;
+        cloned.unguarded = cloned.unguarded.saturating_sub(1);
+        cloned.deeds = 0;
 
         cloned
     }
 
+    /// How well the nihilists know the settlement as a whole, between 0 and 1
+    pub fn general_intel(&self) -> f32 {
+        let undercover = self
+            .operatives
+            .iter()
+            .filter(|o| o.role == Role::Undercover && o.ready())
+            .count();
+
+        (undercover as f32 * UNDERCOVER_INTEL).min(1.0)
+    }
+
+    /// How well the nihilists know a building, helped by the comrades embedded in it
+    pub fn local_intel(&self, building: &Building) -> f32 {
+        let embedded = self
+            .operatives
+            .iter()
+            .filter(|o| o.role.building() == Some(building) && o.ready())
+            .count();
+
+        (self.general_intel() + embedded as f32 * EMBEDDED_INTEL).min(1.0)
+    }
+
+    /// A count as the nihilists hear it, through their comrades in a building if it concerns one
+    pub fn rumour(&mut self, count: usize, building: Option<&Building>) -> String {
+        let quality = match building {
+            Some(building) => self.local_intel(building),
+            None => self.general_intel(),
+        };
+
+        Intel::rumour(count, quality, &mut self.rng)
+    }
+
+    /// Gathers whatever the operatives can find out about the economy
+    pub fn observe(&mut self, economy: &Economy) {
+        let general = self.general_intel();
+        let local: HashMap<_, _> = Building::iter()
+            .map(|b| (b.resource(), self.local_intel(&b)))
+            .collect();
+
+        self.intel
+            .observe(economy, general, |r| local[r], &mut self.rng);
+    }
+
+    /// Takes in a new recruit, unless the rivals got to them first. The more suspicious the
+    /// government, the more likely it is to have planted them.
+    pub fn recruit(&mut self) {
+        if self.rng.gen::<f32>() < self.competition {
+            return;
+        }
+
+        let mut recruit = Operative::random(&mut self.rng);
+        recruit.informant = self.rng.gen::<f32>() < INFORMANT_LIKELIHOOD * (1.0 + self.suspicion);
+
+        self.enlist(recruit);
+    }
+
+    /// Takes an operative into the first cell with room for them, under a fresh id
+    pub fn enlist(&mut self, mut operative: Operative) {
+        operative.id = self.next_id;
+        self.next_id += 1;
+        operative.cell = self.network.place(&self.operatives);
+        self.operatives.push(operative);
+    }
+
+    /// Informants might tip off the police, who round up their cell, informant and all to keep
+    /// their cover. Safe houses let some of the comrades slip away. Returns how many comrades were
+    /// arrested in every raid.
+    pub fn inform(&mut self) -> Vec<usize> {
+        let informants: Vec<usize> = self
+            .operatives
+            .iter()
+            .filter(|o| o.informant)
+            .map(|o| o.id)
+            .collect();
+
+        let mut raids = vec![];
+        for id in informants {
+            // Earlier raids might have taken them already
+            let Some(informant) = self.operatives.iter().find(|o| o.id == id) else {
+                continue;
+            };
+            if self.rng.gen::<f32>() >= INFORMANT_REPORT_LIKELIHOOD {
+                continue;
+            }
+
+            let mut named = Network::linked(informant.cell, false, &self.operatives);
+            if self.learned(&Doctrine::SafeHouses) {
+                named.shuffle(&mut self.rng);
+                named.truncate(named.len() / 2);
+            }
+            let arrested = self.take(named);
+
+            self.suspicion = (self.suspicion + INFORMANT_SUSPICION).min(1.0);
+            if !arrested.is_empty() {
+                raids.push(arrested.len());
+                self.prosecute(arrested, Charge::Conspiracy);
+            }
+        }
+
+        raids
+    }
+
+    /// Counterspies hunt for informants. Returns the names of the unmasked.
+    pub fn unmask(&mut self) -> Vec<String> {
+        let vigilance: Vec<f32> = self
+            .operatives
+            .iter()
+            .filter(|o| o.role == Role::Counterspy && o.ready())
+            .map(|o| o.skill())
+            .collect();
+
+        let mut unmasked = vec![];
+        for skill in vigilance {
+            let informants: Vec<usize> = (0..self.operatives.len())
+                .filter(|&i| self.operatives[i].informant)
+                .collect();
+            if informants.is_empty() {
+                break;
+            }
+
+            if self.rng.gen::<f32>() < COUNTERSPY_LIKELIHOOD * skill * self.effectiveness() {
+                let pick = informants[self.rng.gen_range(0..informants.len())];
+                unmasked.push(self.operatives.remove(pick).name);
+                self.credit(&Role::Counterspy);
+            }
+        }
+
+        unmasked
+    }
+
+    /// Efficiency as modified by how the network is organised
+    pub fn effectiveness(&self) -> f32 {
+        self.efficiency * self.network.modifier()
+    }
+
+    /// Number of operatives in a role, settled in or not
+    pub fn count(&self, role: &Role) -> usize {
+        self.operatives.iter().filter(|o| &o.role == role).count()
+    }
+
+    /// Number of operatives still on their way into a role
+    pub fn pending(&self, role: &Role) -> usize {
+        self.operatives
+            .iter()
+            .filter(|o| &o.role == role && !o.ready())
+            .count()
+    }
+
+    /// Operatives still on their way into their roles, soonest first
+    pub fn orders(&self) -> Vec<&Operative> {
+        let mut orders: Vec<&Operative> = self.operatives.iter().filter(|o| !o.ready()).collect();
+        orders.sort_by_key(|o| o.busy);
+        orders
+    }
+
+    /// Combined skill of the operatives in a role, sharpened by doctrine
+    pub fn skill(&self, role: &Role) -> f32 {
+        let doctrine = match role {
+            Role::Agitator(_) => Some(Doctrine::Rhetoric),
+            Role::Saboteur(_) => Some(Doctrine::Explosives),
+            Role::Embezzler(_) => Some(Doctrine::ForgedLedgers),
+            _ => None,
+        };
+
+        let boost = match doctrine {
+            Some(doctrine) if self.learned(&doctrine) => DOCTRINE_BOOST,
+            _ => 1.0,
+        };
+
+        self.operatives
+            .iter()
+            .filter(|o| &o.role == role && o.ready())
+            .map(|o| o.skill())
+            .sum::<f32>()
+            * boost
+    }
+
+    pub fn learned(&self, doctrine: &Doctrine) -> bool {
+        self.doctrines.contains(doctrine)
+    }
+
+    /// Whether the doctrine is not learned yet but everything it builds on is
+    pub fn learnable(&self, doctrine: &Doctrine) -> bool {
+        !self.learned(doctrine)
+            && doctrine
+                .requires()
+                .is_none_or(|required| self.learned(&required))
+    }
+
+    /// Learns a doctrine by spending efficiency points. Returns whether it could be afforded.
+    pub fn learn_with_points(&mut self, doctrine: &Doctrine) -> bool {
+        if !self.learnable(doctrine) || self.efficiency - doctrine.points() < 1.0 {
+            return false;
+        }
+
+        self.efficiency -= doctrine.points();
+        self.doctrines.push(doctrine.clone());
+        true
+    }
+
+    /// Learns a doctrine by spending rubles. Returns whether it could be afforded.
+    pub fn learn_with_rubles(&mut self, doctrine: &Doctrine) -> bool {
+        if !self.learnable(doctrine) || self.treasury < doctrine.rubles() {
+            return false;
+        }
+
+        self.treasury -= doctrine.rubles();
+        self.doctrines.push(doctrine.clone());
+        true
+    }
+
+    /// Pays for a bribe to be delivered tomorrow. Returns whether it could be afforded.
+    pub fn bribe(&mut self, bribe: &Bribe) -> bool {
+        if self.treasury < bribe.cost() {
+            return false;
+        }
+
+        self.treasury -= bribe.cost();
+        self.bribes.push(bribe.clone());
+        true
+    }
+
+    /// The police arrest a random operative in the role. Returns their name and how many comrades
+    /// they gave up, if anyone was found.
+    pub fn arrest(&mut self, role: &Role) -> Option<(String, usize)> {
+        let suspects: Vec<usize> = (0..self.operatives.len())
+            .filter(|&i| &self.operatives[i].role == role)
+            .collect();
+        if suspects.is_empty() {
+            return None;
+        }
+
+        let pick = suspects[self.rng.gen_range(0..suspects.len())];
+        let arrested = self.operatives.remove(pick);
+        let name = arrested.name.clone();
+        let exposed = self.interrogate(&arrested);
+        self.prosecute(vec![arrested], Charge::Murder);
+
+        Some((name, exposed))
+    }
+
+    /// Interrogates an operative already taken away. Returns how many comrades were arrested.
+    fn interrogate(&mut self, arrested: &Operative) -> usize {
+        let mut named = self
+            .network
+            .interrogate(arrested, &self.operatives, &mut self.rng);
+        if self.learned(&Doctrine::SafeHouses) {
+            named.shuffle(&mut self.rng);
+            named.truncate(named.len() / 2);
+        }
+
+        let exposed = self.take(named);
+        let count = exposed.len();
+        self.prosecute(exposed, Charge::Conspiracy);
+
+        count
+    }
+
+    /// Puts arrested comrades on trial
+    fn prosecute(&mut self, defendants: Vec<Operative>, charge: Charge) {
+        if !defendants.is_empty() {
+            self.trials.push(Trial::new(defendants, charge));
+        }
+    }
+
+    /// Acquitted comrades lie low for a while before getting back to work
+    pub fn release(&mut self, defendants: Vec<Operative>) {
+        for mut operative in defendants {
+            operative.recall();
+            self.enlist(operative);
+        }
+    }
+
+    /// The executed live on as martyrs
+    pub fn martyr(&mut self, executed: usize) {
+        self.martyrs += executed;
+        self.martyrdom += executed as f32;
+    }
+
+    /// Turns embezzled goods into rubles
+    pub fn stash(&mut self, embezzled: usize) {
+        let mut rubles = embezzled * EMBEZZLED_RUBLES;
+        if self.learned(&Doctrine::Counterfeiting) {
+            rubles *= 2;
+        }
+
+        self.treasury += rubles;
+    }
+
+    /// How much more storage a fire destroys
+    pub fn blast(&self) -> usize {
+        if self.learned(&Doctrine::Dynamite) {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Orders the undercover operative best suited for the role into it. Those still lying low
+    /// after their last job are left alone.
+    pub fn assign(&mut self, role: Role) {
+        let skill = role.skill();
+
+        if let Some(operative) = self
+            .operatives
+            .iter_mut()
+            .filter(|o| o.role == Role::Undercover && o.ready())
+            .max_by(|a, b| match &skill {
+                Some(skill) => a.skill_at(skill).total_cmp(&b.skill_at(skill)),
+                None => std::cmp::Ordering::Equal,
+            })
+        {
+            operative.order(role);
+        }
+    }
+
+    /// Recalls the settled operative least suited for the role. Those still on their way in have
+    /// to settle first.
+    pub fn unassign(&mut self, role: &Role) {
+        if let Some(operative) = self
+            .operatives
+            .iter_mut()
+            .filter(|o| &o.role == role && o.ready())
+            .min_by(|a, b| a.skill().total_cmp(&b.skill()))
+        {
+            operative.recall();
+        }
+    }
+
+    /// Credits a random settled operative in the role with a success
+    fn credit(&mut self, role: &Role) {
+        let count = self.count(role) - self.pending(role);
+        if count == 0 {
+            return;
+        }
+
+        let pick = self.rng.gen_range(0..count);
+        if let Some(operative) = self
+            .operatives
+            .iter_mut()
+            .filter(|o| &o.role == role && o.ready())
+            .nth(pick)
+        {
+            operative.succeed();
+        }
+    }
+
+    /// Takes away the operatives at the given positions. Returns the taken.
+    fn take(&mut self, mut positions: Vec<usize>) -> Vec<Operative> {
+        positions.sort_unstable_by(|a, b| b.cmp(a));
+        positions.dedup();
+
+        let mut taken: Vec<Operative> = positions
+            .into_iter()
+            .map(|i| self.operatives.remove(i))
+            .collect();
+        taken.reverse();
+        taken
+    }
+
+    /// Efficient agitators weigh on production all the more, but never talk anyone into working
+    /// harder
     pub fn agitator_modifier(&self, building: &Building) -> f32 {
-        (AGITATOR_MODIFIER * self.efficiency).powi(*self.agitators.get(building).unwrap() as i32)
+        AGITATOR_MODIFIER.powf(self.skill(&Role::Agitator(building.clone())) * self.effectiveness())
+    }
+
+    /// How strongly the organisation draws in would-be recruits
+    pub fn appeal(&self) -> f32 {
+        self.skill(&Role::Recruiter) * self.effectiveness() + self.reach()
+    }
+
+    /// How many people the propaganda campaign gets to
+    pub fn reach(&self) -> f32 {
+        self.skill(&Role::Propagandist) * self.effectiveness() * self.campaign.reach()
+    }
+
+    /// How much the agitators' talk of greener pastures and the propaganda weigh on the
+    /// settlement's morale
+    pub fn discontent(&self) -> f32 {
+        let agitation = Building::iter()
+            .map(|b| self.skill(&Role::Agitator(b)))
+            .sum::<f32>()
+            * AGITATOR_DISCONTENT
+            * self.effectiveness();
+
+        agitation
+            + self.reach() * self.campaign.theme.morale() * PROPAGANDA_MORALE
+            + self.martyrdom * MARTYR_DISCONTENT
     }
 
     pub fn sabotaged(&mut self, building: &Building) -> bool {
-        let sabotage_likelihood =
-            *self.saboteurs.get(building).unwrap() as f32 * SABOTEUR_LIKELIHOOD * self.efficiency;
+        let role = Role::Saboteur(building.clone());
+        let sabotage_likelihood = self.skill(&role) * SABOTEUR_LIKELIHOOD * self.effectiveness();
 
-        self.rng.gen::<f32>() < sabotage_likelihood
+        let sabotaged = self.rng.gen::<f32>() < sabotage_likelihood;
+        if sabotaged {
+            self.credit(&role);
+            self.deeds += 1;
+        }
+
+        sabotaged
+    }
+
+    /// Raiders lie in wait on the roads out of the settlement. Returns whether they fell upon a
+    /// passing caravan.
+    pub fn ambushed(&mut self) -> bool {
+        let ambush_likelihood =
+            self.skill(&Role::Raider) * RAIDER_LIKELIHOOD * self.effectiveness();
+
+        let ambushed = self.rng.gen::<f32>() < ambush_likelihood;
+        if ambushed {
+            self.credit(&Role::Raider);
+            self.deeds += 1;
+            self.suspicion = (self.suspicion + RAIDER_SUSPICION).min(1.0);
+        }
+
+        ambushed
     }
 
     pub fn embezzlement(&self, building: &Building) -> f32 {
-        (EMBEZZLER_MODIFIER * self.efficiency).powi(*self.embezzlers.get(building).unwrap() as i32)
+        EMBEZZLER_MODIFIER
+            .powf(self.skill(&Role::Embezzler(building.clone())) * self.effectiveness())
+    }
+
+    /// Plaguebearers spread sickness among the workers of their building, at the risk of catching
+    /// it themselves and carrying it back to their cell. Returns how many workers got infected and
+    /// how many comrades were lost.
+    pub fn infect(&mut self, building: &Building) -> (usize, usize) {
+        let role = Role::Plaguebearer(building.clone());
+        let effectiveness = self.effectiveness();
+
+        let mut infected = 0;
+        let mut sick = vec![];
+        for i in 0..self.operatives.len() {
+            if self.operatives[i].role != role || !self.operatives[i].ready() {
+                continue;
+            }
+
+            let infect_likelihood =
+                PLAGUEBEARER_LIKELIHOOD * self.operatives[i].skill() * effectiveness;
+            if self.rng.gen::<f32>() < infect_likelihood {
+                infected += PLAGUEBEARER_INFECTIONS;
+                self.operatives[i].succeed();
+            }
+
+            if self.rng.gen::<f32>() < PLAGUEBEARER_RISK {
+                sick.push(i);
+                for j in Network::linked(self.operatives[i].cell, false, &self.operatives) {
+                    if j != i && self.rng.gen::<f32>() < PLAGUEBEARER_CONTAGION {
+                        sick.push(j);
+                    }
+                }
+            }
+        }
+
+        let lost = self.take(sick).len();
+        (infected, lost)
     }
 
-    pub fn hit(&mut self) -> usize {
-        let hit_likelihood = HITMAN_LIKELIHOOD * self.efficiency;
+    /// Every hitman might make an attempt on `target`, the current target unless it's out of
+    /// reach. Hitmen who get killed or captured are lost, and the captured might give up their
+    /// comrades.
+    pub fn hit(&mut self, target: &Target) -> Vec<Hit> {
+        let hit_likelihood = HITMAN_LIKELIHOOD * self.effectiveness();
 
-        (0..self.hitmen)
+        let attempts: Vec<usize> = self
+            .operatives
+            .iter()
+            .filter(|o| o.role == Role::Hitman && o.ready())
+            .map(|o| o.id)
+            .collect::<Vec<usize>>()
+            .into_iter()
             .filter(|_| self.rng.gen::<f32>() < hit_likelihood)
-            .count()
+            .collect();
+
+        let mut hits = vec![];
+        for id in attempts {
+            // Others might have been given up by their captured comrades
+            let Some(i) = self.operatives.iter().position(|o| o.id == id) else {
+                continue;
+            };
+            let name = self.operatives[i].name.clone();
+
+            let mut risk = target.risk();
+            if self.unguarded > 0 {
+                risk *= UNGUARDED_RISK;
+            }
+            if self.learned(&Doctrine::EscapeRoutes) {
+                risk *= 0.5;
+            }
+            risk *= 1.0 + self.suspicion;
+
+            let success = (target.success() * self.operatives[i].skill()).min(1.0);
+
+            let outcome = if self.rng.gen::<f32>() < success {
+                Outcome::Success
+            } else if self.rng.gen::<f32>() >= risk {
+                Outcome::Escaped
+            } else if self.rng.gen::<f32>() < CAPTURE_LIKELIHOOD {
+                Outcome::Captured
+            } else {
+                Outcome::Killed
+            };
+
+            let mut exposed = 0;
+            match outcome {
+                Outcome::Success => {
+                    self.operatives[i].succeed();
+                    self.deeds += 1;
+                    if *target == Target::Guard {
+                        self.unguarded = GUARD_DAYS;
+                    }
+                }
+                Outcome::Killed => {
+                    self.operatives.remove(i);
+                }
+                Outcome::Captured => {
+                    let captured = self.operatives.remove(i);
+                    exposed = self.interrogate(&captured);
+                    self.prosecute(vec![captured], Charge::Murder);
+                }
+                Outcome::Escaped => {}
+            }
+
+            hits.push(Hit {
+                name,
+                target: target.clone(),
+                outcome,
+                exposed,
+            });
+        }
+
+        hits
     }
 }
 
 impl Default for Nihilists {
     fn default() -> Self {
-        Self {
-            agitators: Building::iter().map(|b| (b, 0)).collect(),
-            saboteurs: Building::iter().map(|b| (b, 0)).collect(),
-            embezzlers: Building::iter().map(|b| (b, 0)).collect(),
+        Self::with_operatives(10)
+    }
+}
+
+impl Nihilists {
+    /// A fresh organisation of `operatives` undercover comrades
+    pub fn with_operatives(operatives: usize) -> Self {
+        let mut rng = rand::thread_rng();
+        let recruits: Vec<Operative> = (0..operatives)
+            .map(|_| Operative::random(&mut rng))
+            .collect();
+
+        let mut nihilists = Self {
+            operatives: vec![],
+
+            target: Target::Citizen,
+            unguarded: 0,
 
-            recruiters: 0,
-            hitmen: 0,
+            network: Network::default(),
 
-            undercover: 10,
             efficiency: 1.0,
-            rng: rand::thread_rng(),
+
+            doctrines: vec![],
+            treasury: 0,
+
+            intel: Intel::default(),
+
+            campaign: Campaign::default(),
+            suspicion: 0.0,
+
+            bribes: vec![],
+
+            trials: vec![],
+            martyrs: 0,
+            martyrdom: 0.0,
+
+            competition: 0.0,
+            deeds: 0,
+
+            next_id: 0,
+
+            rng,
+        };
+        for recruit in recruits {
+            nihilists.enlist(recruit);
         }
+
+        nihilists
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn efficiency_strengthens_agitators_and_embezzlers() {
+        let mut nihilists = Nihilists::default();
+        for (operative, role) in nihilists.operatives.iter_mut().zip([
+            Role::Agitator(Building::Hunting),
+            Role::Embezzler(Building::Hunting),
+        ]) {
+            operative.role = role;
+            operative.busy = 0;
+        }
+
+        let agitation = nihilists.agitator_modifier(&Building::Hunting);
+        let embezzlement = nihilists.embezzlement(&Building::Hunting);
+        assert!(agitation < 1.0);
+        assert!(embezzlement < 1.0);
+
+        nihilists.efficiency = MAX_EFFICIENCY;
+        assert!(nihilists.agitator_modifier(&Building::Hunting) < agitation);
+        assert!(nihilists.embezzlement(&Building::Hunting) < embezzlement);
     }
 }
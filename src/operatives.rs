@@ -0,0 +1,251 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum::{AsRefStr, EnumIter, IntoEnumIterator};
+
+use crate::economy::Building;
+
+const FIRST_NAMES: [&str; 16] = [
+    "Vera", "Sergei", "Sofia", "Nikolai", "Ivan", "Anna", "Dmitri", "Olga", "Pyotr", "Katya",
+    "Mikhail", "Lidia", "Andrei", "Yelena", "Grigori", "Masha",
+];
+const LAST_NAMES: [&str; 12] = [
+    "Zasulich",
+    "Nechayev",
+    "Perovskaya",
+    "Kibalchich",
+    "Zhelyabov",
+    "Figner",
+    "Mikhailov",
+    "Kravchinsky",
+    "Morozov",
+    "Tikhomirov",
+    "Ulyanov",
+    "Osinsky",
+];
+const MIN_SKILL: f32 = 0.5;
+const MAX_SKILL: f32 = 1.5;
+const SKILL_STEP: f32 = 0.05;
+const RECALL_DAYS: usize = 3;
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EnumIter, AsRefStr, Serialize, Deserialize)]
+pub enum Skill {
+    Recruiting,
+    Killing,
+    Propaganda,
+    Vigilance,
+    Agitation,
+    Sabotage,
+    Embezzlement,
+    Plague,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Role {
+    Undercover,
+    Recruiter,
+    Hitman,
+    Propagandist,
+    Counterspy,
+    Raider,
+    Agitator(Building),
+    Saboteur(Building),
+    Embezzler(Building),
+    Plaguebearer(Building),
+}
+
+impl Role {
+    /// Every role an operative can be assigned to
+    pub fn all() -> Vec<Self> {
+        let mut roles = vec![
+            Self::Undercover,
+            Self::Recruiter,
+            Self::Hitman,
+            Self::Propagandist,
+            Self::Counterspy,
+            Self::Raider,
+        ];
+        roles.extend(Building::iter().map(Self::Agitator));
+        roles.extend(Building::iter().map(Self::Saboteur));
+        roles.extend(Building::iter().map(Self::Embezzler));
+        roles.extend(Building::iter().map(Self::Plaguebearer));
+        roles
+    }
+
+    /// The role after this one, cycling back to the start
+    pub fn next(&self) -> Self {
+        let roles = Self::all();
+        let i = roles.iter().position(|r| r == self).unwrap();
+        roles[(i + 1) % roles.len()].clone()
+    }
+
+    /// The role before this one, cycling back to the end
+    pub fn prev(&self) -> Self {
+        let roles = Self::all();
+        let i = roles.iter().position(|r| r == self).unwrap();
+        roles[(i + roles.len() - 1) % roles.len()].clone()
+    }
+
+    /// Days it takes to take up the role. Going back undercover is a recall instead, see
+    /// `Operative::recall`.
+    pub fn delay(&self) -> usize {
+        match self {
+            Self::Undercover => 0,
+            Self::Recruiter => 2,
+            Self::Hitman => 3,
+            Self::Propagandist => 2,
+            Self::Counterspy => 3,
+            Self::Raider => 4,
+            Self::Agitator(_) => 2,
+            Self::Saboteur(_) => 5,
+            Self::Embezzler(_) => 4,
+            Self::Plaguebearer(_) => 3,
+        }
+    }
+
+    pub fn short(&self) -> String {
+        match self {
+            Self::Undercover => "🥸".to_string(),
+            Self::Recruiter => "🤝".to_string(),
+            Self::Hitman => "🔪".to_string(),
+            Self::Propagandist => "📰".to_string(),
+            Self::Counterspy => "🔍".to_string(),
+            Self::Raider => "🏴".to_string(),
+            Self::Agitator(b) => format!("📣{}", b.resource().emoji()),
+            Self::Saboteur(b) => format!("🧨{}", b.resource().emoji()),
+            Self::Embezzler(b) => format!("💰{}", b.resource().emoji()),
+            Self::Plaguebearer(b) => format!("🦠{}", b.resource().emoji()),
+        }
+    }
+
+    /// The building the role is embedded in, if any
+    pub fn building(&self) -> Option<&Building> {
+        match self {
+            Self::Agitator(b) | Self::Saboteur(b) | Self::Embezzler(b) | Self::Plaguebearer(b) => {
+                Some(b)
+            }
+            _ => None,
+        }
+    }
+
+    /// The skill the role relies on, if any
+    pub fn skill(&self) -> Option<Skill> {
+        match self {
+            Self::Undercover => None,
+            Self::Recruiter => Some(Skill::Recruiting),
+            Self::Hitman => Some(Skill::Killing),
+            Self::Propagandist => Some(Skill::Propaganda),
+            Self::Counterspy => Some(Skill::Vigilance),
+            Self::Raider => Some(Skill::Sabotage),
+            Self::Agitator(_) => Some(Skill::Agitation),
+            Self::Saboteur(_) => Some(Skill::Sabotage),
+            Self::Embezzler(_) => Some(Skill::Embezzlement),
+            Self::Plaguebearer(_) => Some(Skill::Plague),
+        }
+    }
+}
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Undercover => write!(f, "🥸 Undercover"),
+            Self::Recruiter => write!(f, "🤝 Recruiter"),
+            Self::Hitman => write!(f, "🔪 Hitman"),
+            Self::Propagandist => write!(f, "📰 Propagandist"),
+            Self::Counterspy => write!(f, "🔍 Counterspy"),
+            Self::Raider => write!(f, "🏴 Raider"),
+            Self::Agitator(b) => write!(f, "📣 Agitator {}", b.resource().emoji()),
+            Self::Saboteur(b) => write!(f, "🧨 Saboteur {}", b.resource().emoji()),
+            Self::Embezzler(b) => write!(f, "💰 Embezzler {}", b.resource().emoji()),
+            Self::Plaguebearer(b) => write!(f, "🦠 Plaguebearer {}", b.resource().emoji()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Operative {
+    /// Tells apart operatives of the same settlement, names are not unique
+    pub id: usize,
+    pub name: String,
+    pub role: Role,
+    pub skills: HashMap<Skill, f32>,
+    pub loyalty: f32,
+    pub experience: usize,
+    /// Days until the operative settles into their role
+    pub busy: usize,
+    /// Secretly reporting to the state, and never let on to anyone looking in from outside
+    #[serde(skip_serializing, default)]
+    pub informant: bool,
+    /// The cell the operative belongs to, see `Network`
+    pub cell: usize,
+}
+
+impl Operative {
+    /// A fresh recruit with a random name and talents
+    pub fn random<R: Rng>(rng: &mut R) -> Self {
+        Self {
+            id: 0,
+            name: format!(
+                "{} {}",
+                FIRST_NAMES[rng.gen_range(0..FIRST_NAMES.len())],
+                LAST_NAMES[rng.gen_range(0..LAST_NAMES.len())]
+            ),
+            role: Role::Undercover,
+            skills: Skill::iter()
+                .map(|s| (s, rng.gen_range(MIN_SKILL..=1.0)))
+                .collect(),
+            loyalty: rng.gen_range(0.5..=1.0),
+            experience: 0,
+            busy: 0,
+            informant: false,
+            cell: 0,
+        }
+    }
+
+    /// Whether the operative has settled into their role
+    pub fn ready(&self) -> bool {
+        self.busy == 0
+    }
+
+    /// Orders the operative into a new role, which takes a while to become effective. Those still
+    /// settling into their last role refuse. Returns whether the order was taken.
+    pub fn order(&mut self, role: Role) -> bool {
+        if !self.ready() {
+            return false;
+        }
+
+        if role == Role::Undercover {
+            self.recall();
+        } else {
+            self.busy = role.delay();
+            self.role = role;
+        }
+        true
+    }
+
+    /// Sends the operative back undercover, where they lie low for a while before they can be
+    /// given another role
+    pub fn recall(&mut self) {
+        self.busy = RECALL_DAYS;
+        self.role = Role::Undercover;
+    }
+
+    /// How good the operative is at their current role
+    pub fn skill(&self) -> f32 {
+        self.role.skill().map(|s| self.skill_at(&s)).unwrap_or(1.0)
+    }
+
+    pub fn skill_at(&self, skill: &Skill) -> f32 {
+        *self.skills.get(skill).unwrap()
+    }
+
+    /// A job well done in the current role
+    pub fn succeed(&mut self) {
+        self.experience += 1;
+
+        if let Some(skill) = self.role.skill() {
+            let skill = self.skills.get_mut(&skill).unwrap();
+            *skill = (*skill + SKILL_STEP).min(MAX_SKILL);
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, EnumIter};
+
+use crate::economy::Resource;
+
+/// Families housed by each longhouse
+pub const LONGHOUSE_HOUSING: usize = 5;
+
+#[derive(Clone, Debug, PartialEq, Eq, EnumIter, AsRefStr, Serialize, Deserialize)]
+pub enum Project {
+    Longhouse, // +population_cap
+    Granary,   // +food storage
+    Woodshed,  // +wood storage
+    Stoneyard, // +stone storage
+    Armoury,   // +iron storage
+    Forge,     // +efficiency
+}
+
+impl Project {
+    /// The resource consumed by the project and how much of it is needed
+    pub fn cost(&self) -> (Resource, usize) {
+        match self {
+            Self::Longhouse => (Resource::Wood, 20),
+            Self::Granary => (Resource::Stone, 15),
+            Self::Woodshed => (Resource::Wood, 10),
+            Self::Stoneyard => (Resource::Stone, 20),
+            Self::Armoury => (Resource::Stone, 20),
+            Self::Forge => (Resource::Iron, 10),
+        }
+    }
+
+    /// The store the project adds capacity to, if any
+    pub fn expands(&self) -> Option<Resource> {
+        match self {
+            Self::Granary => Some(Resource::Food),
+            Self::Woodshed => Some(Resource::Wood),
+            Self::Stoneyard => Some(Resource::Stone),
+            Self::Armoury => Some(Resource::Iron),
+            Self::Longhouse | Self::Forge => None,
+        }
+    }
+
+    /// Days it takes to complete the project once started
+    pub fn duration(&self) -> usize {
+        match self {
+            Self::Longhouse => 4,
+            Self::Granary => 4,
+            Self::Woodshed => 3,
+            Self::Stoneyard => 5,
+            Self::Armoury => 5,
+            Self::Forge => 6,
+        }
+    }
+
+    pub fn headline(&self) -> String {
+        match self {
+            Self::Longhouse => format!(
+                "New longhouse built! Room for {} more families.",
+                LONGHOUSE_HOUSING
+            ),
+            Self::Granary => "Granary extended, more room for the harvest.".to_string(),
+            Self::Woodshed => "New woodshed stacked to the rafters.".to_string(),
+            Self::Stoneyard => "Stoneyard walls pushed back to fit more stone.".to_string(),
+            Self::Armoury => "Armoury expansion complete, more room for iron.".to_string(),
+            Self::Forge => "New forge lit! Better tools for every worker.".to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Construction {
+    pub project: Project,
+    pub days_left: usize,
+}
+
+impl Construction {
+    pub fn new(project: Project) -> Self {
+        Self {
+            days_left: project.duration(),
+            project,
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+use strum::EnumIter;
+
+/// Days it takes a campaign to reach everyone it's going to reach
+const CAMPAIGN_RAMP: usize = 14;
+const PRINTED_REACH: f32 = 2.0;
+
+#[derive(Clone, Debug, PartialEq, Eq, EnumIter, Serialize, Deserialize)]
+pub enum Theme {
+    Bread,
+    Land,
+    Equality,
+    Atheism,
+}
+
+impl Theme {
+    /// The theme after this one, cycling back to the start
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Bread => Self::Land,
+            Self::Land => Self::Equality,
+            Self::Equality => Self::Atheism,
+            Self::Atheism => Self::Bread,
+        }
+    }
+
+    /// How hard the theme weighs on morale
+    pub fn morale(&self) -> f32 {
+        match self {
+            Self::Bread => 1.5,
+            Self::Land => 1.0,
+            Self::Equality => 0.5,
+            Self::Atheism => 1.0,
+        }
+    }
+
+    /// How many hearts and minds the theme wins over
+    pub fn recruitment(&self) -> f32 {
+        match self {
+            Self::Bread => 0.5,
+            Self::Land => 1.0,
+            Self::Equality => 1.5,
+            Self::Atheism => 1.0,
+        }
+    }
+
+    /// How much the theme gets on the government's nerves
+    pub fn suspicion(&self) -> f32 {
+        match self {
+            Self::Bread => 0.5,
+            Self::Land => 1.0,
+            Self::Equality => 1.0,
+            Self::Atheism => 2.0,
+        }
+    }
+}
+
+impl std::fmt::Display for Theme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bread => write!(f, "🍞 Bread"),
+            Self::Land => write!(f, "🌾 Land"),
+            Self::Equality => write!(f, "⚖️ Equality"),
+            Self::Atheism => write!(f, "⛪ Atheism"),
+        }
+    }
+}
+
+/// The propagandists' current campaign, which gains momentum the longer it runs
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Campaign {
+    pub theme: Theme,
+    pub days: usize,
+    /// Whether the treasury could pay for pamphlets today
+    pub printed: bool,
+}
+
+impl Default for Campaign {
+    fn default() -> Self {
+        Self {
+            theme: Theme::Bread,
+            days: 0,
+            printed: false,
+        }
+    }
+}
+
+impl Campaign {
+    /// Reach per unit of propagandist skill
+    pub fn reach(&self) -> f32 {
+        let momentum = (self.days as f32 / CAMPAIGN_RAMP as f32).min(1.0);
+
+        if self.printed {
+            momentum * PRINTED_REACH
+        } else {
+            momentum
+        }
+    }
+
+    /// Switches to the next theme, starting over from scratch
+    pub fn switch(&mut self) {
+        self.theme = self.theme.next();
+        self.days = 0;
+    }
+}
@@ -0,0 +1,112 @@
+use rand::{prelude::ThreadRng, Rng};
+use serde::{Deserialize, Serialize};
+
+use crate::{nihilists::Nihilists, operatives::Role};
+
+/// Share of the rivals' operatives they like to keep in each role
+const PLAN: [(Role, f32); 3] = [
+    (Role::Recruiter, 0.4),
+    (Role::Propagandist, 0.3),
+    (Role::Hitman, 0.3),
+];
+const WAYLAY_LIKELIHOOD: f32 = 0.01;
+const CLAIM_LIKELIHOOD: f32 = 0.2;
+
+/// A rival faction in the settlement, run by the computer. They go after the same recruits and
+/// have no love for our comrades.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Rivals {
+    pub name: String,
+    pub nihilists: Nihilists,
+    #[serde(skip)]
+    rng: ThreadRng,
+}
+
+impl Rivals {
+    pub fn new(name: &str, operatives: usize) -> Self {
+        Self {
+            name: name.to_string(),
+            nihilists: Nihilists::with_operatives(operatives),
+            rng: rand::thread_rng(),
+        }
+    }
+
+    /// Splits the day's would-be recruits with our comrades: whoever draws more people in gets
+    /// to more of them
+    pub fn compete(&mut self, ours: &mut Nihilists) {
+        let (appeal, rival_appeal) = (ours.appeal(), self.nihilists.appeal());
+        if appeal + rival_appeal > 0.0 {
+            ours.competition = rival_appeal / (appeal + rival_appeal);
+            self.nihilists.competition = appeal / (appeal + rival_appeal);
+        }
+    }
+
+    /// A day in the rivals' life. Our comrades might get set upon, and today's outrages claimed
+    /// by the wrong people.
+    pub fn next(&self, ours: &mut Nihilists, day: usize) -> (Self, Vec<String>) {
+        let mut cloned = self.clone();
+        let mut headlines = vec![];
+
+        cloned.plan();
+
+        // Their hitmen have it in for our comrades
+        let waylay_likelihood = cloned.nihilists.skill(&Role::Hitman)
+            * WAYLAY_LIKELIHOOD
+            * cloned.nihilists.effectiveness();
+        if cloned.rng.gen::<f32>() < waylay_likelihood {
+            let ready = ours.operatives.iter().filter(|o| o.ready()).count();
+            if ready > 0 {
+                let pick = cloned.rng.gen_range(0..ready);
+                if let Some(operative) = ours.operatives.iter_mut().filter(|o| o.ready()).nth(pick)
+                {
+                    operative.recall();
+                    headlines.push(format!(
+                        "[Day {:>3}] {} set upon by the {}, lies low for a while.",
+                        day, operative.name, cloned.name
+                    ));
+                }
+            }
+        }
+
+        // The papers print whoever shouts loudest
+        let claim_likelihood = (cloned.nihilists.reach() * CLAIM_LIKELIHOOD).min(1.0);
+        for _ in 0..ours.deeds {
+            if cloned.rng.gen::<f32>() < claim_likelihood {
+                cloned.nihilists.recruit();
+                headlines.push(format!(
+                    "[Day {:>3}] The {} claim responsibility for the latest outrage.",
+                    day, cloned.name
+                ));
+            }
+        }
+
+        cloned.nihilists = cloned.nihilists.next();
+
+        (cloned, headlines)
+    }
+
+    /// Recalls a comrade from wherever the plan is furthest ahead, and sends one with nothing to
+    /// do wherever it is furthest behind
+    fn plan(&mut self) {
+        let total = self.nihilists.operatives.len() as f32;
+
+        let deficits: Vec<(&Role, f32)> = PLAN
+            .iter()
+            .map(|(role, share)| (role, share * total - self.nihilists.count(role) as f32))
+            .collect();
+
+        let ahead = deficits.iter().min_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((role, deficit)) = ahead {
+            if *deficit <= -1.0 {
+                self.nihilists.unassign(role);
+            }
+        }
+
+        let behind = deficits.iter().max_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((role, deficit)) = behind {
+            if *deficit >= 1.0 {
+                self.nihilists.assign((*role).clone());
+            }
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// The screens the game can show, cycled through with Tab
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Screen {
+    #[default]
+    Main,
+    Map,
+    Network,
+    Roster,
+    Doctrines,
+    Bribery,
+}
+
+impl Screen {
+    /// The screen after this one, cycling back to the start
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Main => Self::Map,
+            Self::Map => Self::Network,
+            Self::Network => Self::Roster,
+            Self::Roster => Self::Doctrines,
+            Self::Doctrines => Self::Bribery,
+            Self::Bribery => Self::Main,
+        }
+    }
+}
@@ -1,105 +1,400 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
 use crate::{
+    bribery::Bribe,
+    doctrines::Doctrine,
     economy::{Building, Economy},
+    governor::Order,
     nihilists::Nihilists,
+    operatives::Role,
+    screen::Screen,
+    world::{Settlement, World},
 };
 
-#[derive(Debug, Default)]
+/// Columns in the nihilists panel: roles outside of any building, then one per building
+pub const COLUMNS: usize = 5;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct State {
-    pub economy: Economy,
-    pub nihilists: Nihilists,
+    pub world: World,
+    /// The settlement on screen
+    pub settlement: usize,
+    /// Where operatives are sent from the roster, as an index into the settlement's neighbours
+    pub destination: usize,
     pub control: (usize, usize),
-    pub headlines: Vec<String>,
+    pub screen: Screen,
+    /// Operative under the cursor on the roster screen
+    pub selected: usize,
+    /// Doctrine under the cursor on the doctrines screen
+    pub doctrine: usize,
+    /// Bribe under the cursor on the bribery screen
+    pub bribe: usize,
+    /// Buildings this player staffs in co-op games, or every building if not set
+    #[serde(skip)]
+    pub owned: Option<Vec<Building>>,
 }
 
 impl State {
-    pub fn next(&self) -> Self {
-        let mut nihilists = self.nihilists.clone();
+    pub fn seeded(seed: u64) -> Self {
+        Self {
+            world: World::new(Some(seed)),
+            ..Default::default()
+        }
+    }
 
-        let (economy, mut headlines) = self.economy.next(&mut nihilists);
+    pub fn next(&self) -> Self {
+        let world = self.world.next();
 
-        headlines.extend(self.headlines.iter().cloned());
+        let operatives = world.settlements[self.settlement]
+            .nihilists
+            .operatives
+            .len();
+        let selected = self.selected.min(operatives.saturating_sub(1));
 
         Self {
-            economy,
-            nihilists: nihilists.next(),
+            world,
+            settlement: self.settlement,
+            destination: self.destination,
             control: self.control,
-            headlines,
+            screen: self.screen.clone(),
+            selected,
+            doctrine: self.doctrine,
+            bribe: self.bribe,
+            owned: self.owned.clone(),
+        }
+    }
+
+    /// Whether the player may staff roles in a building, or outside of any building
+    pub fn owns(&self, building: Option<&Building>) -> bool {
+        match (&self.owned, building) {
+            (Some(owned), Some(building)) => owned.contains(building),
+            _ => true,
+        }
+    }
+
+    /// Moves the operative under the cursor on the roster along the roles the player owns
+    fn reassign(&mut self, step: fn(&Role) -> Role) {
+        let Some(operative) = self.nihilists().operatives.get(self.selected) else {
+            return;
+        };
+        if !self.owns(operative.role.building()) {
+            return;
+        }
+
+        let mut role = step(&operative.role);
+        while !self.owns(role.building()) {
+            role = step(&role);
+        }
+
+        let selected = self.selected;
+        self.nihilists_mut().operatives[selected].order(role);
+    }
+
+    pub fn current(&self) -> &Settlement {
+        &self.world.settlements[self.settlement]
+    }
+
+    pub fn economy(&self) -> &Economy {
+        &self.current().economy
+    }
+
+    pub fn nihilists(&self) -> &Nihilists {
+        &self.current().nihilists
+    }
+
+    fn nihilists_mut(&mut self) -> &mut Nihilists {
+        &mut self.world.settlements[self.settlement].nihilists
+    }
+
+    pub fn headlines(&self) -> &[String] {
+        &self.current().headlines
+    }
+
+    /// The game is over once every settlement has died out
+    pub fn extinct(&self) -> bool {
+        self.world.extinct()
+    }
+
+    /// Where operatives are sent from the roster, and the days it takes to get there
+    pub fn destination(&self) -> Option<(usize, usize)> {
+        self.world
+            .neighbours(self.settlement)
+            .get(self.destination)
+            .cloned()
+    }
+
+    /// Sends the operative under the cursor on the roster to the destination
+    pub fn travel(&mut self) {
+        if self.screen != Screen::Roster {
+            return;
+        }
+
+        let owned = self
+            .nihilists()
+            .operatives
+            .get(self.selected)
+            .is_some_and(|o| self.owns(o.role.building()));
+
+        if let Some((to, _)) = self.destination().filter(|_| owned) {
+            self.world.travel(self.settlement, self.selected, to);
+            self.selected = self
+                .selected
+                .min(self.nihilists().operatives.len().saturating_sub(1));
         }
     }
 
     pub fn up(&mut self) {
+        if self.screen == Screen::Map {
+            if self.settlement > 0 {
+                self.settlement -= 1;
+                self.selected = 0;
+                self.destination = 0;
+            }
+            return;
+        }
+
+        if self.screen == Screen::Roster {
+            self.selected = self.selected.saturating_sub(1);
+            return;
+        }
+
+        if self.screen == Screen::Doctrines {
+            self.doctrine = self.doctrine.saturating_sub(1);
+            return;
+        }
+
+        if self.screen == Screen::Bribery {
+            self.bribe = self.bribe.saturating_sub(1);
+            return;
+        }
+
         if self.control.1 > 0 {
             self.control.1 -= 1
         }
     }
 
     pub fn down(&mut self) {
-        if self.control.1 < 2 {
+        if self.screen == Screen::Map {
+            if self.settlement + 1 < self.world.settlements.len() {
+                self.settlement += 1;
+                self.selected = 0;
+                self.destination = 0;
+            }
+            return;
+        }
+
+        if self.screen == Screen::Roster {
+            if self.selected + 1 < self.nihilists().operatives.len() {
+                self.selected += 1;
+            }
+            return;
+        }
+
+        if self.screen == Screen::Doctrines {
+            if self.doctrine + 1 < Doctrine::iter().count() {
+                self.doctrine += 1;
+            }
+            return;
+        }
+
+        if self.screen == Screen::Bribery {
+            if self.bribe + 1 < Bribe::iter().count() {
+                self.bribe += 1;
+            }
+            return;
+        }
+
+        // The overall column has two rows more than the buildings
+        if self.control.1 + 1 < Self::rows(self.control.0) {
             self.control.1 += 1
         }
     }
 
     pub fn left(&mut self) {
+        if self.screen == Screen::Map {
+            self.destination = self.destination.saturating_sub(1);
+            return;
+        }
+
+        if self.screen == Screen::Roster {
+            self.reassign(Role::prev);
+            return;
+        }
+
         if self.control.0 > 0 {
             self.control.0 -= 1
         }
     }
 
     pub fn right(&mut self) {
-        if self.control.0 < 4 {
-            self.control.0 += 1
+        if self.screen == Screen::Map {
+            if self.destination + 1 < self.world.neighbours(self.settlement).len() {
+                self.destination += 1;
+            }
+            return;
         }
-    }
 
-    pub fn plus(&mut self) {
-        if self.nihilists.undercover == 0 {
+        if self.screen == Screen::Roster {
+            self.reassign(Role::next);
             return;
         }
 
-        match self.control {
-            (0, 0) => return,
-            (0, 1) => self.nihilists.recruiters += 1,
-            (0, 2) => self.nihilists.hitmen += 1,
-            (building, num) => {
-                let building = Building::from(building - 1);
+        if self.control.0 + 1 < COLUMNS {
+            self.control.0 += 1;
+            self.control.1 = self.control.1.min(Self::rows(self.control.0) - 1);
+        }
+    }
+
+    pub fn switch_screen(&mut self) {
+        self.screen = self.screen.next();
+    }
 
-                let nihilists: &mut usize = match num {
-                    0 => self.nihilists.agitators.get_mut(&building).unwrap(),
-                    1 => self.nihilists.saboteurs.get_mut(&building).unwrap(),
-                    2 => self.nihilists.embezzlers.get_mut(&building).unwrap(),
-                    _ => unreachable!(),
-                };
+    /// Carries out a decree of the governor of a settlement. Returns whether it was carried out.
+    pub fn govern(&mut self, settlement: usize, order: Order) -> bool {
+        self.world
+            .settlements
+            .get_mut(settlement)
+            .is_some_and(|s| s.economy.governor.apply(order))
+    }
+
+    /// Drafts labour into the next building in the settlement on screen
+    pub fn labour(&mut self) {
+        let order = self.economy().governor.next_labour();
+        self.govern(self.settlement, order);
+    }
+
+    /// Invests in the next project in the settlement on screen
+    pub fn invest(&mut self) {
+        let order = self.economy().governor.next_investment();
+        self.govern(self.settlement, order);
+    }
+
+    /// Posts more guards in the settlement on screen
+    pub fn secure(&mut self) {
+        let order = Order::Security(self.economy().governor.security + 1);
+        self.govern(self.settlement, order);
+    }
+
+    /// Calls guards off the streets of the settlement on screen
+    pub fn relax(&mut self) {
+        let order = Order::Security(self.economy().governor.security.saturating_sub(1));
+        self.govern(self.settlement, order);
+    }
 
-                *nihilists += 1;
+    pub fn retarget(&mut self) {
+        let nihilists = self.nihilists_mut();
+        nihilists.target = nihilists.target.next();
+    }
+
+    pub fn campaign(&mut self) {
+        self.nihilists_mut().campaign.switch();
+    }
+
+    /// Pays rubles from the treasury for the doctrine or bribe under the cursor
+    pub fn pay(&mut self) {
+        match self.screen {
+            Screen::Doctrines => {
+                if let Some(doctrine) = Doctrine::iter().nth(self.doctrine) {
+                    self.nihilists_mut().learn_with_rubles(&doctrine);
+                }
+            }
+            Screen::Bribery => {
+                if let Some(bribe) = Bribe::iter().nth(self.bribe) {
+                    self.nihilists_mut().bribe(&bribe);
+                }
             }
+            _ => {}
         }
+    }
 
-        self.nihilists.undercover -= 1;
+    /// Rows in a column of the nihilists panel
+    pub fn rows(column: usize) -> usize {
+        if column == 0 {
+            6
+        } else {
+            4
+        }
     }
 
-    pub fn minus(&mut self) {
+    /// The role under the cursor in the nihilists panel, if any
+    fn controlled_role(&self) -> Option<Role> {
         match self.control {
-            (0, 0) => return,
-            (0, 1) if self.nihilists.recruiters > 0 => self.nihilists.recruiters -= 1,
-            (0, 2) if self.nihilists.hitmen > 0 => self.nihilists.hitmen -= 1,
+            (0, 1) => Some(Role::Recruiter),
+            (0, 2) => Some(Role::Hitman),
+            (0, 3) => Some(Role::Propagandist),
+            (0, 4) => Some(Role::Counterspy),
+            (0, 5) => Some(Role::Raider),
+            (0, _) => None,
             (building, num) => {
-                let building = Building::from(building - 1);
-
-                let nihilists: &mut usize = match num {
-                    0 => self.nihilists.agitators.get_mut(&building).unwrap(),
-                    1 => self.nihilists.saboteurs.get_mut(&building).unwrap(),
-                    2 => self.nihilists.embezzlers.get_mut(&building).unwrap(),
-                    _ => unreachable!(),
-                };
-
-                if *nihilists > 0 {
-                    *nihilists -= 1;
-                } else {
-                    return;
+                let building = Building::iter().nth(building - 1)?;
+
+                match num {
+                    0 => Some(Role::Agitator(building)),
+                    1 => Some(Role::Saboteur(building)),
+                    2 => Some(Role::Embezzler(building)),
+                    3 => Some(Role::Plaguebearer(building)),
+                    _ => None,
                 }
             }
         }
+    }
+
+    pub fn plus(&mut self) {
+        if self.screen == Screen::Network {
+            self.nihilists_mut().network.larger();
+            return;
+        }
+
+        if self.screen == Screen::Doctrines {
+            if let Some(doctrine) = Doctrine::iter().nth(self.doctrine) {
+                self.nihilists_mut().learn_with_points(&doctrine);
+            }
+            return;
+        }
+
+        if self.screen != Screen::Main {
+            return;
+        }
+
+        if let Some(role) = self.controlled_role().filter(|r| self.owns(r.building())) {
+            self.nihilists_mut().assign(role);
+        }
+    }
+
+    pub fn minus(&mut self) {
+        if self.screen == Screen::Network {
+            self.nihilists_mut().network.smaller();
+            return;
+        }
+
+        if self.screen != Screen::Main {
+            return;
+        }
+
+        if let Some(role) = self.controlled_role().filter(|r| self.owns(r.building())) {
+            self.nihilists_mut().unassign(&role);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_role_off_the_panel() {
+        let mut state = State::seeded(1);
+
+        for control in [(COLUMNS, 0), (1, 4), (0, 6)] {
+            state.control = control;
+            assert_eq!(state.controlled_role(), None);
+        }
 
-        self.nihilists.undercover += 1;
+        state.control = (1, 0);
+        assert_eq!(
+            state.controlled_role(),
+            Some(Role::Agitator(Building::Hunting))
+        );
     }
 }
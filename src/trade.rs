@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+use crate::economy::Resource;
+
+/// Share of a store above which a settlement is willing to part with its goods
+pub const SURPLUS: f32 = 0.5;
+/// Share of a store below which a settlement goes looking for goods
+pub const SHORTAGE: f32 = 0.25;
+/// Most a caravan can carry
+pub const CARAVAN_CAPACITY: usize = 40;
+/// Entries kept in the ledger
+const LEDGER_LENGTH: usize = 50;
+
+/// Goods on their way from one settlement to another, by index
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Caravan {
+    pub from: usize,
+    pub to: usize,
+    pub resource: Resource,
+    pub amount: usize,
+    /// Length of the journey in days
+    pub days: usize,
+    pub days_left: usize,
+}
+
+impl Caravan {
+    /// How far along the road the caravan is, between 0 and 1
+    pub fn progress(&self) -> f64 {
+        (self.days - self.days_left) as f64 / self.days as f64
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Fate {
+    Delivered,
+    Ambushed,
+}
+
+impl std::fmt::Display for Fate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Delivered => write!(f, "📦 Delivered"),
+            Self::Ambushed => write!(f, "🏴 Ambushed"),
+        }
+    }
+}
+
+/// A caravan that reached the end of the road, one way or another
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Entry {
+    pub day: usize,
+    pub caravan: Caravan,
+    pub fate: Fate,
+}
+
+/// The latest caravans to reach the end of the road, latest first, and the running totals of
+/// every caravan that ever did
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Ledger {
+    pub entries: Vec<Entry>,
+    delivered: usize,
+    ambushed: usize,
+}
+
+impl Ledger {
+    pub fn record(&mut self, day: usize, caravan: Caravan, fate: Fate) {
+        match fate {
+            Fate::Delivered => self.delivered += caravan.amount,
+            Fate::Ambushed => self.ambushed += caravan.amount,
+        }
+
+        self.entries.insert(0, Entry { day, caravan, fate });
+        self.entries.truncate(LEDGER_LENGTH);
+    }
+
+    /// Goods that made it through and goods lost on the road
+    pub fn totals(&self) -> (usize, usize) {
+        (self.delivered, self.ambushed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn caravan(amount: usize) -> Caravan {
+        Caravan {
+            from: 0,
+            to: 1,
+            resource: Resource::Food,
+            amount,
+            days: 3,
+            days_left: 0,
+        }
+    }
+
+    #[test]
+    fn totals_outlast_the_entries() {
+        let mut ledger = Ledger::default();
+        for day in 0..LEDGER_LENGTH * 2 {
+            ledger.record(day, caravan(10), Fate::Delivered);
+        }
+        ledger.record(100, caravan(5), Fate::Ambushed);
+
+        assert_eq!(ledger.entries.len(), LEDGER_LENGTH);
+        assert_eq!(ledger.entries[0].fate, Fate::Ambushed);
+        assert_eq!(ledger.totals(), (LEDGER_LENGTH * 2 * 10, 5));
+    }
+
+    #[test]
+    fn progress_runs_from_start_to_end() {
+        let mut caravan = caravan(10);
+        caravan.days_left = 3;
+        assert_eq!(caravan.progress(), 0.0);
+
+        caravan.days_left = 0;
+        assert_eq!(caravan.progress(), 1.0);
+    }
+}
@@ -0,0 +1,77 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::operatives::Operative;
+
+pub const TRIAL_DAYS: usize = 5;
+const ACQUITTAL_LIKELIHOOD: f32 = 0.1;
+const CORRUPT_ACQUITTAL: f32 = 0.3;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Charge {
+    Murder,
+    Conspiracy,
+}
+
+impl Charge {
+    /// Likelihood of the defendants hanging if found guilty
+    pub fn execution(&self) -> f32 {
+        match self {
+            Self::Murder => 0.7,
+            Self::Conspiracy => 0.2,
+        }
+    }
+}
+
+impl std::fmt::Display for Charge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Murder => write!(f, "murder"),
+            Self::Conspiracy => write!(f, "conspiracy"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Acquitted,
+    Exiled,
+    Executed,
+}
+
+/// Captured comrades standing trial together
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Trial {
+    pub defendants: Vec<Operative>,
+    pub charge: Charge,
+    pub days_left: usize,
+}
+
+impl Trial {
+    pub fn new(defendants: Vec<Operative>, charge: Charge) -> Self {
+        Self {
+            defendants,
+            charge,
+            days_left: TRIAL_DAYS,
+        }
+    }
+
+    /// The defendants as named in the papers
+    pub fn names(&self) -> String {
+        match self.defendants.len() {
+            1 => self.defendants[0].name.clone(),
+            n => format!("{} and {} others", self.defendants[0].name, n - 1),
+        }
+    }
+
+    /// The court's verdict. Corrupt courts are easier to walk out of.
+    pub fn verdict<R: Rng>(&self, corruption: f32, rng: &mut R) -> Verdict {
+        if rng.gen::<f32>() < ACQUITTAL_LIKELIHOOD + corruption * CORRUPT_ACQUITTAL {
+            Verdict::Acquitted
+        } else if rng.gen::<f32>() < self.charge.execution() {
+            Verdict::Executed
+        } else {
+            Verdict::Exiled
+        }
+    }
+}
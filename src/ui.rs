@@ -3,23 +3,43 @@ use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout},
     style::{Color, Style},
-    widgets::{Block, Borders, Gauge, List, ListItem},
+    widgets::{
+        canvas::{Canvas, Line},
+        Block, Borders, Gauge, List, ListItem,
+    },
     Frame,
 };
 
 use crate::{
-    economy::{Building, Season},
+    bribery::Bribe,
+    doctrines::Doctrine,
+    economy::{Building, Resource, Season},
+    intel::{Intel, Report},
+    network::Network,
+    operatives::Role,
+    screen::Screen,
     state::State,
 };
 
 pub fn draw<B: Backend>(frame: &mut Frame<B>, state: &State) {
+    match state.screen {
+        Screen::Main => draw_main(frame, state),
+        Screen::Map => draw_map(frame, state),
+        Screen::Network => draw_network(frame, state),
+        Screen::Roster => draw_roster(frame, state),
+        Screen::Doctrines => draw_doctrines(frame, state),
+        Screen::Bribery => draw_bribery(frame, state),
+    }
+}
+
+fn draw_main<B: Backend>(frame: &mut Frame<B>, state: &State) {
     // Screen
     let layout = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
         .constraints([
-            Constraint::Length(8),
-            Constraint::Length(8),
+            Constraint::Length(10),
+            Constraint::Length(14),
             Constraint::Percentage(30),
         ])
         .split(frame.size());
@@ -32,10 +52,18 @@ pub fn draw<B: Backend>(frame: &mut Frame<B>, state: &State) {
         .split(layout[0]);
 
     //------ Calendar ------------------------------------------------------------------------------
-    let season = Season::from(state.economy.day);
+    let calendar = state.economy().calendar();
+    let season = Season::from(calendar);
     frame.render_widget(
         Block::default()
-            .title(format!("📆 Day {} - {}", state.economy.day, season))
+            .title(format!(
+                "📆 {} - Year {} Day {} - {} - {}",
+                state.current().name,
+                Season::year(calendar),
+                Season::day_of_year(calendar),
+                season,
+                state.economy().weather
+            ))
             .borders(Borders::ALL),
         top_layout[0],
     );
@@ -48,22 +76,59 @@ pub fn draw<B: Backend>(frame: &mut Frame<B>, state: &State) {
 
     let mut list_items = vec![];
 
-    // Population
-    list_items.push(ListItem::new(format!(
-        "👪 Population  {}",
-        state.economy.population
-    )));
+    // What the nihilists know, rather than what is
+    let intel = &state.nihilists().intel;
+    let today = state.economy().day;
+
+    // Population, down to the last child only when the intel is clear
+    let population = intel.population.as_ref();
+    list_items.push(ListItem::new(match population {
+        Some(report) if report.error == 0 && report.day == today => format!(
+            "👪 Population  {} (👶 {} 🧑 {} 👴 {} 🤒 {})",
+            state.economy().population.total(),
+            state.economy().population.children,
+            state.economy().population.workers,
+            state.economy().population.elders,
+            state.economy().infected,
+        ),
+        _ => format!("👪 Population  {}", Intel::estimate(population, today)),
+    }));
 
     // Population Cap
     list_items.push(ListItem::new(format!(
         "🏠 Housing     {}",
-        state.economy.population_cap
+        Intel::estimate(intel.housing.as_ref(), today)
     )));
 
     // Population Cap
     list_items.push(ListItem::new(format!(
-        "🏭 Efficiency  {:.1}%",
-        state.economy.efficiency * 100.0
+        "🏭 Efficiency  {}%  😐 Morale {}%",
+        Intel::estimate(intel.efficiency.as_ref(), today),
+        Intel::estimate(intel.morale.as_ref(), today)
+    )));
+
+    // Forecast
+    list_items.push(ListItem::new(format!(
+        "🔭 Tomorrow    {}",
+        state.economy().forecast
+    )));
+
+    // Constructions
+    list_items.push(ListItem::new(format!(
+        "🏗️  Building    {}",
+        state
+            .economy()
+            .constructions
+            .iter()
+            .map(|c| format!("{} ({}d)", c.project.as_ref(), c.days_left))
+            .collect::<Vec<String>>()
+            .join(", ")
+    )));
+
+    // Governor
+    list_items.push(ListItem::new(format!(
+        "🏛️  Governor    {}",
+        state.economy().governor
     )));
 
     frame.render_widget(List::new(list_items), calender_layout[0]);
@@ -88,42 +153,68 @@ pub fn draw<B: Backend>(frame: &mut Frame<B>, state: &State) {
         ])
         .split(top_layout[1]);
 
+    let granary = intel.capacities.get(&Resource::Food);
+    let woodshed = intel.capacities.get(&Resource::Wood);
+    let stoneyard = intel.capacities.get(&Resource::Stone);
+    let armoury = intel.capacities.get(&Resource::Iron);
+
+    let food = intel.stockpiles.get(&Resource::Food);
+    let wood = intel.stockpiles.get(&Resource::Wood);
+    let stone = intel.stockpiles.get(&Resource::Stone);
+    let iron = intel.stockpiles.get(&Resource::Iron);
+
+    // Gauges fill up to the best estimates
+    let percent = |report: Option<&Report>, capacity: Option<&Report>| {
+        let capacity = capacity.map_or(0, |r| r.value);
+        if capacity == 0 {
+            return 0;
+        }
+
+        std::cmp::min(100, 100 * report.map_or(0, |r| r.value) / capacity) as u16
+    };
+
     let food_gauge = Gauge::default()
         .block(Block::default())
         .gauge_style(Style::default().fg(Color::LightRed))
         .label(format!(
-            "🍖 {:>4}/{}",
-            state.economy.food, state.economy.storage
+            "🍖 Granary   {:>4}/{} 🦠 -{}",
+            Intel::estimate(food, today),
+            Intel::approximate(granary),
+            Intel::approximate(intel.spoiled.as_ref())
         ))
-        .percent((100 * state.economy.food / state.economy.storage) as u16);
+        .percent(percent(food, granary));
     frame.render_widget(food_gauge, storage_layout[0]);
 
     let wood_gauge = Gauge::default()
         .gauge_style(Style::default().fg(Color::LightGreen))
         .label(format!(
-            "🪵 {:>4}/{}",
-            state.economy.wood, state.economy.storage
+            "🪵 Woodshed  {:>4}/{} 🔥 -{}",
+            Intel::estimate(wood, today),
+            Intel::approximate(woodshed),
+            Intel::approximate(intel.burned.as_ref())
         ))
-        .percent((100 * state.economy.wood / state.economy.storage) as u16);
+        .percent(percent(wood, woodshed));
     frame.render_widget(wood_gauge, storage_layout[1]);
 
     let stone_gauge = Gauge::default()
         .gauge_style(Style::default().fg(Color::Gray))
         .label(format!(
-            "🪨 {:>4}/{}",
-            state.economy.stone, state.economy.storage
+            "🪨 Stoneyard {:>4}/{}",
+            Intel::estimate(stone, today),
+            Intel::approximate(stoneyard)
         ))
-        .percent((100 * state.economy.stone / state.economy.storage) as u16);
+        .percent(percent(stone, stoneyard));
     frame.render_widget(stone_gauge, storage_layout[2]);
 
     let iron_gauge = Gauge::default()
         .block(Block::default())
         .gauge_style(Style::default().fg(Color::LightYellow))
         .label(format!(
-            "🪙 {:>4}/{}",
-            state.economy.iron, state.economy.storage
+            "🪙 Armoury   {:>4}/{}",
+            Intel::estimate(iron, today),
+            Intel::approximate(armoury)
         ))
-        .percent((100 * state.economy.iron / state.economy.storage) as u16);
+        .percent(percent(iron, armoury));
     frame.render_widget(iron_gauge, storage_layout[3]);
     //----------------------------------------------------------------------------------------------
 
@@ -139,11 +230,12 @@ pub fn draw<B: Backend>(frame: &mut Frame<B>, state: &State) {
         .direction(Direction::Horizontal)
         .margin(1)
         .constraints([
-            Constraint::Percentage(20),
-            Constraint::Percentage(20),
-            Constraint::Percentage(20),
-            Constraint::Percentage(20),
-            Constraint::Percentage(20),
+            Constraint::Percentage(18),
+            Constraint::Percentage(16),
+            Constraint::Percentage(16),
+            Constraint::Percentage(16),
+            Constraint::Percentage(16),
+            Constraint::Percentage(18),
         ])
         .split(layout[1]);
 
@@ -160,24 +252,61 @@ pub fn draw<B: Backend>(frame: &mut Frame<B>, state: &State) {
             ListItem::new(format!(
                 "{} 🥸 Undercover {:>3}",
                 if state.control == (0, 0) { '▶' } else { ' ' },
-                state.nihilists.undercover,
+                staffing(state, &Role::Undercover),
             )),
             // Recruiters
             ListItem::new(format!(
                 "{} 🤝 Recruiters {:>3}",
                 if state.control == (0, 1) { '▶' } else { ' ' },
-                state.nihilists.recruiters
+                staffing(state, &Role::Recruiter)
             )),
             // Vee cur off your Johnson
             ListItem::new(format!(
                 "{} 🔪 Hitmen {:>7}",
                 if state.control == (0, 2) { '▶' } else { ' ' },
-                state.nihilists.hitmen
+                staffing(state, &Role::Hitman)
+            )),
+            // Propagandists
+            ListItem::new(format!(
+                "{} 📰 Propaganda {:>3}",
+                if state.control == (0, 3) { '▶' } else { ' ' },
+                staffing(state, &Role::Propagandist)
+            )),
+            // Counterspies
+            ListItem::new(format!(
+                "{} 🔍 Counterspies {:>1}",
+                if state.control == (0, 4) { '▶' } else { ' ' },
+                staffing(state, &Role::Counterspy)
+            )),
+            // Raiders
+            ListItem::new(format!(
+                "{} 🏴 Raiders {:>6}",
+                if state.control == (0, 5) { '▶' } else { ' ' },
+                staffing(state, &Role::Raider)
+            )),
+            // Target
+            ListItem::new(format!("  🎯 {}", state.nihilists().target.short())),
+            // Campaign
+            ListItem::new(format!(
+                "  📢 {}{}",
+                state.nihilists().campaign.theme,
+                if state.nihilists().campaign.printed {
+                    " 🖨️"
+                } else {
+                    ""
+                }
             )),
             // Efficiency§
             ListItem::new(format!(
                 "  ⚙️  Efficiency {:.1}%",
-                state.nihilists.efficiency * 100.0
+                state.nihilists().efficiency * 100.0
+            )),
+            // Treasury
+            ListItem::new(format!("  💰 Treasury {} ₽", state.nihilists().treasury)),
+            // Suspicion
+            ListItem::new(format!(
+                "  👁️ Suspicion {:.0}%",
+                state.nihilists().suspicion * 100.0
             )),
         ]),
         nihilists_layout[0],
@@ -195,7 +324,7 @@ pub fn draw<B: Backend>(frame: &mut Frame<B>, state: &State) {
                     } else {
                         ' '
                     },
-                    state.nihilists.agitators.get(&building).unwrap()
+                    staffing(state, &Role::Agitator(building.clone()))
                 )),
                 ListItem::new(format!(
                     "{} 🧨 Saboteurs {}",
@@ -204,7 +333,7 @@ pub fn draw<B: Backend>(frame: &mut Frame<B>, state: &State) {
                     } else {
                         ' '
                     },
-                    state.nihilists.saboteurs.get(&building).unwrap()
+                    staffing(state, &Role::Saboteur(building.clone()))
                 )),
                 ListItem::new(format!(
                     "{} 💰 Embezzlers {}",
@@ -213,28 +342,594 @@ pub fn draw<B: Backend>(frame: &mut Frame<B>, state: &State) {
                     } else {
                         ' '
                     },
-                    state.nihilists.embezzlers.get(&building).unwrap()
+                    staffing(state, &Role::Embezzler(building.clone()))
+                )),
+                ListItem::new(format!(
+                    "{} 🦠 Plaguebearers {}",
+                    if i == state.control.0 && state.control.1 == 3 {
+                        '▶'
+                    } else {
+                        ' '
+                    },
+                    staffing(state, &Role::Plaguebearer(building.clone()))
                 )),
             ])
             .block(
                 Block::default()
-                    .title(building.to_string())
+                    .title(if state.owns(Some(&building)) {
+                        building.to_string()
+                    } else {
+                        format!("{} 🔒", building)
+                    })
                     .borders(Borders::ALL),
             ),
             nihilists_layout[i],
         );
     }
+
+    // Orders, and comrades in the dock
+    frame.render_widget(
+        List::new(
+            state
+                .nihilists()
+                .orders()
+                .iter()
+                .map(|o| ListItem::new(format!("{} {} {}d", o.name, o.role.short(), o.busy)))
+                .chain(
+                    state
+                        .nihilists()
+                        .trials
+                        .iter()
+                        .map(|t| ListItem::new(format!("⚖️ {} {}d", t.names(), t.days_left))),
+                )
+                .collect::<Vec<ListItem>>(),
+        )
+        .block(Block::default().title("⏳ Orders").borders(Borders::ALL)),
+        nihilists_layout[5],
+    );
     //----------------------------------------------------------------------------------------------
 
+    let bottom_layout = Layout::default()
+        .direction(Direction::Horizontal)
+        .margin(0)
+        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+        .split(layout[2]);
+
     frame.render_widget(
         List::new(
             state
-                .headlines
+                .headlines()
                 .iter()
                 .map(|h| ListItem::new(h.to_string()))
                 .collect::<Vec<ListItem>>(),
         )
         .block(Block::default().title("Headlines").borders(Borders::ALL)),
+        bottom_layout[0],
+    );
+
+    //------ Chronicle -----------------------------------------------------------------------------
+    let this_year = &intel.year;
+    let last_year = &intel.last_year;
+
+    let row = |label: &str, this: Option<&Report>, last: Option<&Report>| {
+        ListItem::new(format!(
+            "{:<9} {:>7} {:>7}",
+            label,
+            Intel::approximate(this),
+            if last_year.year > 0 {
+                Intel::approximate(last)
+            } else {
+                "-".to_string()
+            }
+        ))
+    };
+
+    frame.render_widget(
+        List::new(vec![
+            ListItem::new(format!(
+                "{:<9} {:>7} {:>7}",
+                "",
+                format!("Y{}", this_year.year),
+                if last_year.year > 0 {
+                    format!("Y{}", last_year.year)
+                } else {
+                    "-".to_string()
+                }
+            )),
+            row(
+                "👶 Born",
+                this_year.births.as_ref(),
+                last_year.births.as_ref(),
+            ),
+            row(
+                "⚰️  Dead",
+                this_year.deaths.as_ref(),
+                last_year.deaths.as_ref(),
+            ),
+            row(
+                "🧳 Arrived",
+                this_year.immigrants.as_ref(),
+                last_year.immigrants.as_ref(),
+            ),
+            row(
+                "🚶 Left",
+                this_year.emigrants.as_ref(),
+                last_year.emigrants.as_ref(),
+            ),
+            row(
+                "🍖 Food",
+                this_year.production.get(&Resource::Food),
+                last_year.production.get(&Resource::Food),
+            ),
+            row(
+                "🪵 Wood",
+                this_year.production.get(&Resource::Wood),
+                last_year.production.get(&Resource::Wood),
+            ),
+            row(
+                "🪨 Stone",
+                this_year.production.get(&Resource::Stone),
+                last_year.production.get(&Resource::Stone),
+            ),
+            row(
+                "🪙 Iron",
+                this_year.production.get(&Resource::Iron),
+                last_year.production.get(&Resource::Iron),
+            ),
+        ])
+        .block(Block::default().title("📜 Chronicle").borders(Borders::ALL)),
+        bottom_layout[1],
+    );
+    //----------------------------------------------------------------------------------------------
+}
+
+fn draw_map<B: Backend>(frame: &mut Frame<B>, state: &State) {
+    let layout = Layout::default()
+        .direction(Direction::Horizontal)
+        .margin(1)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(frame.size());
+
+    let world = &state.world;
+    let destination = state.destination().map(|(to, _)| to);
+
+    //------ Map -----------------------------------------------------------------------------------
+    frame.render_widget(
+        Canvas::default()
+            .block(Block::default().title("🗺️ Map").borders(Borders::ALL))
+            .x_bounds([0.0, 100.0])
+            .y_bounds([0.0, 100.0])
+            .paint(|ctx| {
+                for route in &world.routes {
+                    let (x1, y1) = world.settlements[route.from].position;
+                    let (x2, y2) = world.settlements[route.to].position;
+                    let chosen = [route.from, route.to].contains(&state.settlement)
+                        && [Some(route.from), Some(route.to)].contains(&destination);
+
+                    ctx.draw(&Line {
+                        x1,
+                        y1,
+                        x2,
+                        y2,
+                        color: if chosen { Color::Yellow } else { Color::Gray },
+                    });
+                }
+
+                ctx.layer();
+
+                for caravan in &world.caravans {
+                    let (x1, y1) = world.settlements[caravan.from].position;
+                    let (x2, y2) = world.settlements[caravan.to].position;
+                    let progress = caravan.progress();
+
+                    ctx.print(
+                        x1 + (x2 - x1) * progress,
+                        y1 + (y2 - y1) * progress,
+                        format!("🐫{}", caravan.resource.emoji()),
+                    );
+                }
+
+                for journey in &world.journeys {
+                    let (x1, y1) = world.settlements[journey.from].position;
+                    let (x2, y2) = world.settlements[journey.to].position;
+                    let progress = journey.progress();
+
+                    ctx.print(x1 + (x2 - x1) * progress, y1 + (y2 - y1) * progress, "🚶");
+                }
+
+                for (i, settlement) in world.settlements.iter().enumerate() {
+                    let (x, y) = settlement.position;
+                    // The nihilists only know as much as their comrades there tell them
+                    let population = if settlement.economy.extinct() {
+                        "☠️".to_string()
+                    } else {
+                        Intel::approximate(settlement.nihilists.intel.population.as_ref())
+                    };
+
+                    ctx.print(
+                        x,
+                        y,
+                        format!(
+                            "{}{} {}",
+                            if i == state.settlement { "▶ " } else { "" },
+                            settlement.name,
+                            population
+                        ),
+                    );
+                }
+            }),
+        layout[0],
+    );
+    //----------------------------------------------------------------------------------------------
+
+    let right_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(0)
+        .constraints([
+            Constraint::Length(world.settlements.len() as u16 + 5),
+            Constraint::Min(0),
+        ])
+        .split(layout[1]);
+
+    //------ Settlements ---------------------------------------------------------------------------
+    let mut list_items = world
+        .settlements
+        .iter()
+        .enumerate()
+        .map(|(i, settlement)| {
+            ListItem::new(format!(
+                "{} {:<9} {} {} 🕵️ {} ⚫ {}",
+                if i == state.settlement { '▶' } else { ' ' },
+                settlement.name,
+                settlement
+                    .economy
+                    .specialisation
+                    .as_ref()
+                    .map_or("".to_string(), |b| b.to_string()),
+                Season::from(settlement.economy.calendar()),
+                settlement.nihilists.operatives.len(),
+                settlement.rivals.nihilists.operatives.len()
+            ))
+        })
+        .collect::<Vec<ListItem>>();
+
+    list_items.push(ListItem::new(""));
+    list_items.push(ListItem::new(match state.destination() {
+        Some((to, days)) => format!(
+            "🛤️ Sending operatives to {} ({}d)",
+            world.settlements[to].name, days
+        ),
+        None => "🛤️ No roads lead out of here".to_string(),
+    }));
+    list_items.push(ListItem::new(
+        "   Press ↑/↓ to pick a settlement, ←/→ to pick a destination, Tab for the network",
+    ));
+
+    frame.render_widget(
+        List::new(list_items).block(
+            Block::default()
+                .title("🏘️ Settlements")
+                .borders(Borders::ALL),
+        ),
+        right_layout[0],
+    );
+    //----------------------------------------------------------------------------------------------
+
+    //------ Ledger --------------------------------------------------------------------------------
+    let (delivered, ambushed) = world.ledger.totals();
+    let mut list_items = vec![ListItem::new(format!(
+        "📦 Delivered {}  🏴 Ambushed {}",
+        delivered, ambushed
+    ))];
+
+    list_items.extend(world.caravans.iter().map(|c| {
+        ListItem::new(format!(
+            "🐫 {:<9} → {:<9} {:>3} {}  {}d left",
+            world.settlements[c.from].name,
+            world.settlements[c.to].name,
+            c.amount,
+            c.resource.emoji(),
+            c.days_left
+        ))
+    }));
+
+    list_items.extend(world.ledger.entries.iter().map(|e| {
+        ListItem::new(format!(
+            "[Day {:>3}] {:<9} → {:<9} {:>3} {}  {}",
+            e.day,
+            world.settlements[e.caravan.from].name,
+            world.settlements[e.caravan.to].name,
+            e.caravan.amount,
+            e.caravan.resource.emoji(),
+            e.fate
+        ))
+    }));
+
+    frame.render_widget(
+        List::new(list_items).block(Block::default().title("📒 Ledger").borders(Borders::ALL)),
+        right_layout[1],
+    );
+    //----------------------------------------------------------------------------------------------
+}
+
+/// Operatives in a role, with those still on their way in
+fn staffing(state: &State, role: &Role) -> String {
+    match state.nihilists().pending(role) {
+        0 => state.nihilists().count(role).to_string(),
+        pending => format!("{} ⏳{}", state.nihilists().count(role), pending),
+    }
+}
+
+fn draw_network<B: Backend>(frame: &mut Frame<B>, state: &State) {
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([Constraint::Length(8), Constraint::Min(0)])
+        .split(frame.size());
+
+    let network = &state.nihilists().network;
+    let operatives = state.nihilists().operatives.len();
+    let handlers = Network::handlers(&state.nihilists().operatives);
+
+    //------ Organisation --------------------------------------------------------------------------
+    frame.render_widget(
+        List::new(vec![
+            ListItem::new(format!("👥 Cell size   {}", network.cell_size)),
+            ListItem::new(format!("🕵️ Operatives  {}", operatives)),
+            ListItem::new(format!(
+                "🔗 Cells       {}",
+                handlers.iter().map(|cells| cells.len()).sum::<usize>()
+            )),
+            ListItem::new(format!("🧑‍💼 Handlers    {}", handlers.len())),
+            ListItem::new(format!(
+                "⚙️  Efficiency  {:+.0}%",
+                (network.modifier() - 1.0) * 100.0
+            )),
+            ListItem::new("   Press +/- to change the cell size, Tab for the roster"),
+        ])
+        .block(Block::default().title("🕸️ Network").borders(Borders::ALL)),
+        layout[0],
+    );
+    //----------------------------------------------------------------------------------------------
+
+    //------ Cells ---------------------------------------------------------------------------------
+    frame.render_widget(
+        List::new(
+            handlers
+                .iter()
+                .enumerate()
+                .map(|(i, cells)| {
+                    ListItem::new(format!(
+                        "🧑‍💼 Handler {:<3} {}",
+                        i + 1,
+                        cells
+                            .iter()
+                            .map(|members| format!("[{}]", "👤".repeat(*members)))
+                            .collect::<Vec<String>>()
+                            .join(" ")
+                    ))
+                })
+                .collect::<Vec<ListItem>>(),
+        )
+        .block(Block::default().title("🔗 Cells").borders(Borders::ALL)),
+        layout[1],
+    );
+    //----------------------------------------------------------------------------------------------
+}
+
+fn draw_roster<B: Backend>(frame: &mut Frame<B>, state: &State) {
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([Constraint::Length(5), Constraint::Min(0)])
+        .split(frame.size());
+
+    //------ Help ----------------------------------------------------------------------------------
+    frame.render_widget(
+        List::new(vec![
+            ListItem::new(format!(
+                "🕯️ Martyrs {}  ⚖️ On trial {}  ⚫ {} {}",
+                state.nihilists().martyrs,
+                state
+                    .nihilists()
+                    .trials
+                    .iter()
+                    .map(|t| t.defendants.len())
+                    .sum::<usize>(),
+                state.current().rivals.name,
+                state.current().rivals.nihilists.operatives.len()
+            )),
+            ListItem::new(
+                "Press ↑/↓ to pick an operative, ←/→ to change the role of a settled one, Tab for doctrines",
+            ),
+            ListItem::new(match state.destination() {
+                Some((to, days)) => format!(
+                    "Press M to send them to {} ({}d), pick another destination on the map",
+                    state.world.settlements[to].name, days
+                ),
+                None => "No roads lead out of here".to_string(),
+            }),
+        ])
+        .block(Block::default().title("📋 Roster").borders(Borders::ALL)),
+        layout[0],
+    );
+    //----------------------------------------------------------------------------------------------
+
+    //------ Operatives ----------------------------------------------------------------------------
+    // Scroll so the selected operative stays in view
+    let rows = layout[1].height.saturating_sub(2) as usize;
+    let scroll = (state.selected + 1).saturating_sub(rows);
+
+    frame.render_widget(
+        List::new(
+            state
+                .nihilists()
+                .operatives
+                .iter()
+                .enumerate()
+                .skip(scroll)
+                .map(|(i, operative)| {
+                    ListItem::new(format!(
+                        "{} {:<22} {:<20} 🔗 Cell {:<3} 🎓 Skill {:>3.0}%  ❤️ Loyalty {:>3.0}%  ⭐ {}",
+                        if i == state.selected { '▶' } else { ' ' },
+                        operative.name,
+                        operative.role.to_string(),
+                        operative.cell + 1,
+                        operative.skill() * 100.0,
+                        operative.loyalty * 100.0,
+                        operative.experience,
+                    ))
+                })
+                .collect::<Vec<ListItem>>(),
+        )
+        .block(
+            Block::default()
+                .title("🕵️ Operatives")
+                .borders(Borders::ALL),
+        ),
+        layout[1],
+    );
+    //----------------------------------------------------------------------------------------------
+}
+
+fn draw_doctrines<B: Backend>(frame: &mut Frame<B>, state: &State) {
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([Constraint::Length(5), Constraint::Min(0)])
+        .split(frame.size());
+
+    let nihilists = &state.nihilists();
+
+    //------ Funds ---------------------------------------------------------------------------------
+    frame.render_widget(
+        List::new(vec![
+            ListItem::new(format!(
+                "⚙️  Efficiency points {:.1}",
+                (nihilists.efficiency - 1.0) * 100.0
+            )),
+            ListItem::new(format!("💰 Treasury          {} ₽", nihilists.treasury)),
+            ListItem::new(
+                "   Press + to learn with efficiency points, $ with rubles, Tab for bribery",
+            ),
+        ])
+        .block(Block::default().title("📚 Doctrines").borders(Borders::ALL)),
+        layout[0],
+    );
+    //----------------------------------------------------------------------------------------------
+
+    //------ Tree ----------------------------------------------------------------------------------
+    frame.render_widget(
+        List::new(
+            Doctrine::iter()
+                .enumerate()
+                .map(|(i, doctrine)| {
+                    ListItem::new(format!(
+                        "{} {}{:<20} {:<42} {}",
+                        if i == state.doctrine { '▶' } else { ' ' },
+                        if doctrine.requires().is_some() {
+                            "  └ "
+                        } else {
+                            ""
+                        },
+                        doctrine.to_string(),
+                        doctrine.description(),
+                        if nihilists.learned(&doctrine) {
+                            "✅ Learned".to_string()
+                        } else if nihilists.learnable(&doctrine) {
+                            format!(
+                                "{:.0} points or {} ₽",
+                                doctrine.points() * 100.0,
+                                doctrine.rubles()
+                            )
+                        } else {
+                            "🔒 Locked".to_string()
+                        },
+                    ))
+                })
+                .collect::<Vec<ListItem>>(),
+        )
+        .block(Block::default().title("🌳 Tree").borders(Borders::ALL)),
+        layout[1],
+    );
+    //----------------------------------------------------------------------------------------------
+}
+
+fn draw_bribery<B: Backend>(frame: &mut Frame<B>, state: &State) {
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(7),
+            Constraint::Length(5),
+            Constraint::Min(0),
+        ])
+        .split(frame.size());
+
+    let nihilists = &state.nihilists();
+
+    //------ Officials -----------------------------------------------------------------------------
+    frame.render_widget(
+        List::new(vec![
+            ListItem::new(format!(
+                "🤑 Corruption {:.0}%",
+                state.economy().corruption * 100.0
+            )),
+            ListItem::new(format!("👁️ Suspicion  {:.0}%", nihilists.suspicion * 100.0)),
+            ListItem::new(format!("💰 Treasury   {} ₽", nihilists.treasury)),
+            ListItem::new(format!(
+                "📨 Pending    {}",
+                nihilists
+                    .bribes
+                    .iter()
+                    .map(|b| b.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )),
+            ListItem::new("   Press $ to pay a bribe, Tab to go back"),
+        ])
+        .block(Block::default().title("💸 Bribery").borders(Borders::ALL)),
+        layout[0],
+    );
+    //----------------------------------------------------------------------------------------------
+
+    //------ Bribes --------------------------------------------------------------------------------
+    frame.render_widget(
+        List::new(
+            Bribe::iter()
+                .enumerate()
+                .map(|(i, bribe)| {
+                    ListItem::new(format!(
+                        "{} {:<18} {:<36} {} ₽",
+                        if i == state.bribe { '▶' } else { ' ' },
+                        bribe.to_string(),
+                        bribe.description(),
+                        bribe.cost()
+                    ))
+                })
+                .collect::<Vec<ListItem>>(),
+        )
+        .block(Block::default().title("🧾 Bribes").borders(Borders::ALL)),
+        layout[1],
+    );
+    //----------------------------------------------------------------------------------------------
+
+    //------ Investigations ------------------------------------------------------------------------
+    frame.render_widget(
+        List::new(
+            state
+                .economy()
+                .investigations
+                .iter()
+                .map(|days| ListItem::new(format!("🔎 Closing in within {} days", days)))
+                .collect::<Vec<ListItem>>(),
+        )
+        .block(
+            Block::default()
+                .title("🔎 Investigations")
+                .borders(Borders::ALL),
+        ),
         layout[2],
     );
+    //----------------------------------------------------------------------------------------------
 }
@@ -0,0 +1,75 @@
+use rand::{rngs::StdRng, Rng};
+use serde::{Deserialize, Serialize};
+use strum::AsRefStr;
+
+use crate::economy::Season;
+
+#[derive(Clone, Debug, PartialEq, Eq, AsRefStr, Serialize, Deserialize)]
+pub enum Weather {
+    Mild,
+    Drought,
+    Storm,
+    Blizzard,
+}
+
+impl Weather {
+    /// Rolls the weather for a day in the given season
+    pub fn roll(season: &Season, rng: &mut StdRng) -> Self {
+        // [Mild, Drought, Storm, Blizzard]
+        let odds = match season {
+            Season::Spring => [0.7, 0.0, 0.3, 0.0],
+            Season::Summer => [0.7, 0.2, 0.1, 0.0],
+            Season::Autumn => [0.6, 0.0, 0.4, 0.0],
+            Season::Winter => [0.7, 0.0, 0.1, 0.2],
+        };
+
+        let mut roll = rng.gen::<f32>();
+
+        for (weather, odds) in [Self::Mild, Self::Drought, Self::Storm, Self::Blizzard]
+            .into_iter()
+            .zip(odds)
+        {
+            if roll < odds {
+                return weather;
+            }
+            roll -= odds;
+        }
+
+        Self::Mild
+    }
+
+    // [Food, Wood, Stone, Iron]
+    pub fn production(&self) -> [f32; 4] {
+        match self {
+            Self::Mild => [1.0, 1.0, 1.0, 1.0],
+            Self::Drought => [0.4, 1.0, 1.1, 1.0],
+            Self::Storm => [0.6, 0.5, 0.7, 1.0],
+            Self::Blizzard => [0.5, 0.2, 0.3, 0.8],
+        }
+    }
+
+    pub fn headline(&self) -> &'static str {
+        match self {
+            Self::Mild => "Skies clear up, work resumes as normal.",
+            Self::Drought => "Drought! Crops wither and game flees the valley.",
+            Self::Storm => "Storm batters the settlement, work grinds to a halt.",
+            Self::Blizzard => "Blizzard! Nobody dares leave their homes.",
+        }
+    }
+}
+
+impl std::fmt::Display for Weather {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.as_ref(),
+            match self {
+                Self::Mild => "🌤️",
+                Self::Drought => "🏜️",
+                Self::Storm => "⛈️",
+                Self::Blizzard => "🌨️",
+            }
+        )
+    }
+}
@@ -0,0 +1,338 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::{
+    economy::{Building, Economy, Resource, Season},
+    nihilists::Nihilists,
+    operatives::Operative,
+    rivals::Rivals,
+    trade::{Caravan, Fate, Ledger, CARAVAN_CAPACITY, SHORTAGE, SURPLUS},
+};
+
+/// The faction competing with the player in every settlement
+const RIVALS: &str = "Black Repartition";
+const RIVAL_OPERATIVES: usize = 3;
+
+/// A town with its own economy and its own cells of nihilists
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Settlement {
+    pub name: String,
+    pub economy: Economy,
+    pub nihilists: Nihilists,
+    pub rivals: Rivals,
+    pub headlines: Vec<String>,
+    /// Where the town lies on the map, between 0 and 100 on either axis
+    pub position: (f64, f64),
+}
+
+impl Settlement {
+    /// Whether the settlement can spare some of a resource, and how much
+    fn surplus(&self, resource: &Resource) -> usize {
+        let spare = (self.economy.capacity(resource) as f32 * SURPLUS) as usize;
+        self.economy.stock(resource).saturating_sub(spare)
+    }
+
+    /// Whether the settlement is running low on a resource
+    fn short(&self, resource: &Resource) -> bool {
+        (self.economy.stock(resource) as f32) < self.economy.capacity(resource) as f32 * SHORTAGE
+    }
+
+    pub fn next(&self) -> Self {
+        // The dead don't make the news
+        if self.economy.extinct() {
+            return self.clone();
+        }
+
+        let mut nihilists = self.nihilists.clone();
+        let mut rivals = self.rivals.clone();
+        rivals.compete(&mut nihilists);
+
+        let (economy, mut headlines) = self.economy.next(&mut nihilists);
+
+        let (rivals, rival_headlines) = rivals.next(&mut nihilists, self.economy.day);
+        headlines.extend(rival_headlines);
+
+        headlines.extend(self.headlines.iter().cloned());
+
+        let mut nihilists = nihilists.next();
+        nihilists.observe(&economy);
+
+        Self {
+            name: self.name.clone(),
+            economy,
+            nihilists,
+            rivals,
+            headlines,
+            position: self.position,
+        }
+    }
+}
+
+/// A road between two settlements, by index, and the days it takes to travel
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Route {
+    pub from: usize,
+    pub to: usize,
+    pub days: usize,
+}
+
+/// An operative on the road from one settlement to another, by index
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Journey {
+    pub operative: Operative,
+    pub from: usize,
+    pub to: usize,
+    /// Length of the journey in days
+    pub days: usize,
+    pub days_left: usize,
+}
+
+impl Journey {
+    /// How far along the road the traveller is, between 0 and 1
+    pub fn progress(&self) -> f64 {
+        (self.days - self.days_left) as f64 / self.days as f64
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct World {
+    pub settlements: Vec<Settlement>,
+    pub routes: Vec<Route>,
+    /// Caravans on the road
+    pub caravans: Vec<Caravan>,
+    /// Operatives on the road
+    pub journeys: Vec<Journey>,
+    pub ledger: Ledger,
+    pub day: usize,
+}
+
+impl Default for World {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl World {
+    /// The settlements of the valley, their weather driven by the given seed if any. The nihilists
+    /// start out in the first one.
+    pub fn new(seed: Option<u64>) -> Self {
+        // Every town is a season further into the year than the last
+        let summer = Season::Spring.length();
+        let autumn = summer + Season::Summer.length();
+
+        let towns = [
+            ("Novgorod", 0, Building::Hunting, (20.0, 70.0)),
+            ("Tver", summer, Building::Quarry, (60.0, 85.0)),
+            ("Tula", autumn, Building::Mine, (75.0, 25.0)),
+        ];
+
+        let settlements = towns
+            .into_iter()
+            .enumerate()
+            .map(|(i, (name, offset, specialisation, position))| {
+                let mut economy = match seed {
+                    Some(seed) => Economy::seeded(seed + i as u64),
+                    None => Economy::default(),
+                };
+                economy.specialisation = Some(specialisation);
+                let economy = economy.settled(offset);
+
+                Settlement {
+                    name: name.to_string(),
+                    economy,
+                    nihilists: Nihilists::with_operatives(if i == 0 { 10 } else { 0 }),
+                    rivals: Rivals::new(RIVALS, RIVAL_OPERATIVES),
+                    headlines: vec![],
+                    position,
+                }
+            })
+            .collect();
+
+        Self {
+            settlements,
+            routes: vec![
+                Route {
+                    from: 0,
+                    to: 1,
+                    days: 3,
+                },
+                Route {
+                    from: 1,
+                    to: 2,
+                    days: 4,
+                },
+                Route {
+                    from: 0,
+                    to: 2,
+                    days: 6,
+                },
+            ],
+            caravans: vec![],
+            journeys: vec![],
+            ledger: Ledger::default(),
+            day: 0,
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        let mut settlements = self.settlements.clone();
+        let mut ledger = self.ledger.clone();
+        let mut caravans = vec![];
+
+        // Caravans on the road, at the mercy of the raiders at either end of it
+        for caravan in &self.caravans {
+            let mut caravan = caravan.clone();
+            caravan.days_left -= 1;
+
+            let raiders = [caravan.from, caravan.to].into_iter().find(|&i| {
+                !settlements[i].economy.extinct() && settlements[i].nihilists.ambushed()
+            });
+
+            if let Some(raiders) = raiders {
+                settlements[raiders].nihilists.stash(caravan.amount);
+
+                let headline = format!(
+                    "[Day {:>3}] Caravan of {} {} from {} ambushed on the road to {}!",
+                    self.day,
+                    caravan.amount,
+                    caravan.resource.emoji(),
+                    settlements[caravan.from].name,
+                    settlements[caravan.to].name
+                );
+                for i in [caravan.from, caravan.to] {
+                    settlements[i].headlines.insert(0, headline.clone());
+                }
+
+                ledger.record(self.day, caravan, Fate::Ambushed);
+            } else if caravan.days_left == 0 {
+                let from = settlements[caravan.from].name.clone();
+                let destination = &mut settlements[caravan.to];
+
+                // Whatever doesn't fit in the stores goes to waste
+                let room = destination
+                    .economy
+                    .capacity(&caravan.resource)
+                    .saturating_sub(destination.economy.stock(&caravan.resource));
+                let stored = if destination.economy.extinct() {
+                    0
+                } else {
+                    caravan.amount.min(room)
+                };
+                *destination.economy.stock_mut(&caravan.resource) += stored;
+
+                let headline = format!(
+                    "[Day {:>3}] Caravan from {} delivers {} {}: {} stored, {} wasted for want of room.",
+                    self.day,
+                    from,
+                    caravan.amount,
+                    caravan.resource.emoji(),
+                    destination.nihilists.rumour(stored, None),
+                    destination.nihilists.rumour(caravan.amount - stored, None)
+                );
+                destination.headlines.insert(0, headline);
+
+                ledger.record(self.day, caravan, Fate::Delivered);
+            } else {
+                caravans.push(caravan);
+            }
+        }
+
+        // The raiders' deeds are in by now, for the rivals to claim
+        let mut settlements: Vec<Settlement> = settlements.iter().map(|s| s.next()).collect();
+
+        // Travellers who made it join the cells at their destination, lying low at first
+        let mut journeys = vec![];
+        for journey in &self.journeys {
+            let mut journey = journey.clone();
+            journey.days_left -= 1;
+
+            if journey.days_left == 0 {
+                journey.operative.recall();
+                settlements[journey.to].nihilists.enlist(journey.operative);
+            } else {
+                journeys.push(journey);
+            }
+        }
+
+        // Surplus goes wherever it's short, one caravan per road and resource at a time
+        for route in &self.routes {
+            for (from, to) in [(route.from, route.to), (route.to, route.from)] {
+                if settlements[from].economy.extinct() || settlements[to].economy.extinct() {
+                    continue;
+                }
+
+                for resource in Resource::iter() {
+                    let underway = caravans
+                        .iter()
+                        .any(|c| c.from == from && c.to == to && c.resource == resource);
+                    let amount = settlements[from].surplus(&resource).min(CARAVAN_CAPACITY);
+
+                    if underway || amount == 0 || !settlements[to].short(&resource) {
+                        continue;
+                    }
+
+                    *settlements[from].economy.stock_mut(&resource) -= amount;
+                    caravans.push(Caravan {
+                        from,
+                        to,
+                        resource,
+                        amount,
+                        days: route.days,
+                        days_left: route.days,
+                    });
+                }
+            }
+        }
+
+        Self {
+            settlements,
+            routes: self.routes.clone(),
+            caravans,
+            journeys,
+            ledger,
+            day: self.day + 1,
+        }
+    }
+
+    /// The game is over once every settlement has died out
+    pub fn extinct(&self) -> bool {
+        self.settlements.iter().all(|s| s.economy.extinct())
+    }
+
+    /// Settlements reachable from a settlement, with the days it takes to get there
+    pub fn neighbours(&self, settlement: usize) -> Vec<(usize, usize)> {
+        self.routes
+            .iter()
+            .filter_map(|r| {
+                if r.from == settlement {
+                    Some((r.to, r.days))
+                } else if r.to == settlement {
+                    Some((r.from, r.days))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Sends an operative down the road. They join the cells at the destination once they've made
+    /// the journey, and lie low for a while after.
+    pub fn travel(&mut self, from: usize, operative: usize, to: usize) {
+        let Some((_, days)) = self.neighbours(from).into_iter().find(|(n, _)| *n == to) else {
+            return;
+        };
+
+        let nihilists = &mut self.settlements[from].nihilists;
+        if operative >= nihilists.operatives.len() {
+            return;
+        }
+
+        self.journeys.push(Journey {
+            operative: nihilists.operatives.remove(operative),
+            from,
+            to,
+            days,
+            days_left: days,
+        });
+    }
+}